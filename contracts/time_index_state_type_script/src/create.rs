@@ -0,0 +1,31 @@
+use ckb_std::{ckb_constants::Source};
+use ckb_std::ckb_types::{bytes::Bytes, prelude::*};
+use ckb_std::high_level::{load_input_out_point, load_script, QueryIter};
+
+use time_info_types::state::{parse_time_index_state_args, TIME_INDEX_STATE_ARGS_OUT_POINT_LEN};
+
+use crate::error::*;
+use crate::helper::{get_script_hash_cell_count, load_time_index_state};
+
+pub fn create(script_hash: [u8; 32]) -> Result<(), Error> {
+    //should only one time index state cell in output
+    if get_script_hash_cell_count(script_hash, Source::Output) != 1 {
+        return Err(Error::InvalidTimeIndexStateOutput);
+    }
+
+    let script = load_script()?;
+    //scrip args cannot empty (txHash + index), optional fields should be valid
+    let script_args: Bytes = script.args().unpack();
+    parse_time_index_state_args(&script_args)?;
+
+    //the out point in args should be consumed by this transaction, like type id
+    let args_out_point = &script_args[..TIME_INDEX_STATE_ARGS_OUT_POINT_LEN];
+    if !QueryIter::new(load_input_out_point, Source::Input).
+        any(|out_point| out_point.as_slice() == args_out_point) {
+        return Err(Error::InvalidArgsOutPoint);
+    }
+
+    //cell data should be valid, index is less than N
+    load_time_index_state(Source::GroupOutput)?;
+    Ok(())
+}
@@ -0,0 +1,17 @@
+use crate::error::*;
+use crate::helper::{has_input_lock_hash, load_time_index_state_args};
+
+pub fn destroy() -> Result<(), Error> {
+    //time index state cell can only be destroyed when admin lock hash is set in args
+    let state_args = load_time_index_state_args()?;
+    let admin_lock_hash = match state_args.admin_lock_hash {
+        Some(lock_hash) => lock_hash,
+        None => return Err(Error::DestroyNotAllowed),
+    };
+
+    //admin should authorize by providing a input cell with admin lock
+    if !has_input_lock_hash(admin_lock_hash) {
+        return Err(Error::InvalidAdminAuthorization);
+    }
+    Ok(())
+}
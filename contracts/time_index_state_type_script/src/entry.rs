@@ -0,0 +1,23 @@
+use core::result::Result;
+
+use ckb_std::ckb_constants::Source;
+use ckb_std::high_level::load_script_hash;
+
+use crate::create::create;
+use crate::destroy::destroy;
+use crate::error::Error;
+use crate::helper::get_script_hash_cell_count;
+use crate::update::update;
+
+pub fn main() -> Result<(), Error> {
+    let script_hash = load_script_hash()?;
+    if get_script_hash_cell_count(script_hash, Source::Input) > 0 {
+        if get_script_hash_cell_count(script_hash, Source::Output) == 0 {
+            destroy()
+        } else {
+            update(script_hash)
+        }
+    } else {
+        create(script_hash)
+    }
+}
@@ -0,0 +1,51 @@
+use ckb_std::error::SysError;
+use time_info_types::error::*;
+
+/// Error, the codes are defined in time_info_types
+#[repr(i8)]
+pub enum Error {
+    IndexOutOfBound = STATE_ERROR_INDEX_OUT_OF_BOUND,
+    ItemMissing = STATE_ERROR_ITEM_MISSING,
+    LengthNotEnough = STATE_ERROR_LENGTH_NOT_ENOUGH,
+    Encoding = STATE_ERROR_ENCODING,
+    InvalidArgument = STATE_ERROR_INVALID_ARGUMENT,
+    InvalidCellData = STATE_ERROR_INVALID_CELL_DATA,
+    InvalidTimeIndexStateInput = STATE_ERROR_INVALID_TIME_INDEX_STATE_INPUT,
+    InvalidTimeIndexStateOutput = STATE_ERROR_INVALID_TIME_INDEX_STATE_OUTPUT,
+    InvalidTimeIndex = STATE_ERROR_INVALID_TIME_INDEX,
+    InvalidRingSize = STATE_ERROR_INVALID_RING_SIZE,
+    InvalidArgsOutPoint = STATE_ERROR_INVALID_ARGS_OUT_POINT,
+    DestroyNotAllowed = STATE_ERROR_DESTROY_NOT_ALLOWED,
+    InvalidAdminAuthorization = STATE_ERROR_INVALID_ADMIN_AUTHORIZATION,
+    InvalidOperatorAuthorization = STATE_ERROR_INVALID_OPERATOR_AUTHORIZATION,
+    UnknownSysError = STATE_ERROR_UNKNOWN_SYS_ERROR,
+    InvalidTimeInfoUpdate = STATE_ERROR_INVALID_TIME_INFO_UPDATE,
+}
+
+impl From<SysError> for Error {
+    fn from(err: SysError) -> Self {
+        use SysError::*;
+        match err {
+            IndexOutOfBound => Self::IndexOutOfBound,
+            ItemMissing => Self::ItemMissing,
+            LengthNotEnough(_) => Self::LengthNotEnough,
+            Encoding => Self::Encoding,
+            Unknown(_) => Self::UnknownSysError,
+        }
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Self {
+        match err {
+            //time index state type script never parses oracle witness
+            ParseError::InvalidArgument | ParseError::InvalidOracleWitness => Self::InvalidArgument,
+            ParseError::EmptyCellData
+            | ParseError::UnknownCellDataVersion(_)
+            | ParseError::InvalidCellDataLength
+            | ParseError::InvalidCellDataFlags
+            | ParseError::InvalidOracleEntries
+            | ParseError::TimeInfoPaused => Self::InvalidCellData,
+        }
+    }
+}
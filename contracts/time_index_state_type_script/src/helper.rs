@@ -0,0 +1,79 @@
+use core::convert::TryFrom;
+
+use ckb_std::{ckb_constants::Source, high_level::*};
+use ckb_std::ckb_types::{bytes::Bytes, prelude::*};
+
+use time_info_types::args::TIME_INFO_ARGS_OUT_POINT_LEN;
+use time_info_types::data::TimeInfoData;
+use time_info_types::state::{parse_time_index_state_args, TimeIndexStateArgs, TimeIndexStateData, TimeInfoFamily};
+
+use crate::error::Error;
+
+pub fn load_time_index_state_args() -> Result<TimeIndexStateArgs, Error> {
+    let script = load_script()?;
+    let script_args: Bytes = script.args().unpack();
+    Ok(parse_time_index_state_args(&script_args)?)
+}
+
+pub fn has_input_lock_hash(lock_hash: [u8; 32]) -> bool {
+    QueryIter::new(load_cell_lock_hash, Source::Input).
+        any(|input_lock_hash| input_lock_hash == lock_hash)
+}
+
+pub fn get_script_hash_cell_count(script_hash: [u8; 32], source: Source) -> usize {
+    QueryIter::new(load_cell_type_hash, source).
+        filter(|type_hash| {
+            match type_hash {
+                Some(type_script_hash) => *type_script_hash == script_hash,
+                None => false
+            }
+        }).
+        count()
+}
+
+pub fn load_time_index_state(source: Source) -> Result<TimeIndexStateData, Error> {
+    let state_data = TimeIndexStateData::try_from(load_cell_data(0, source)?.as_slice())?;
+
+    //ring should have one time info cell at least, and index should be less than N
+    if state_data.ring_size == 0 {
+        return Err(Error::InvalidRingSize);
+    }
+    if state_data.index >= state_data.ring_size {
+        return Err(Error::InvalidTimeIndex);
+    }
+    Ok(state_data)
+}
+
+//whether there is a time info cell of the family with the time index in source
+fn has_family_time_info_cell(family: &TimeInfoFamily, time_index: u8, source: Source) -> Result<bool, Error> {
+    for (index, type_script) in QueryIter::new(load_cell_type, source).enumerate() {
+        let type_script = match type_script {
+            Some(type_script) => type_script,
+            None => continue,
+        };
+        let type_script_args: Bytes = type_script.args().unpack();
+        if type_script.code_hash().as_slice() != family.code_hash
+            || type_script.hash_type().as_slice() != [family.hash_type]
+            || type_script_args.len() < TIME_INFO_ARGS_OUT_POINT_LEN
+            || type_script_args[..TIME_INFO_ARGS_OUT_POINT_LEN] != family.args_out_point {
+            continue;
+        }
+        let cell_data = load_cell_data(index, source)?;
+        if let Ok(time_info_data) = TimeInfoData::try_from(cell_data.as_slice()) {
+            if time_info_data.index == time_index {
+                return Ok(true);
+            }
+        }
+    }
+    Ok(false)
+}
+
+//time info cell of the new time index should be updated in the same transaction, so that
+//time index state never points to a stale time info cell
+pub fn time_info_update_check(family: &TimeInfoFamily, time_index: u8) -> Result<(), Error> {
+    if !has_family_time_info_cell(family, time_index, Source::Input)?
+        || !has_family_time_info_cell(family, time_index, Source::Output)? {
+        return Err(Error::InvalidTimeInfoUpdate);
+    }
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use ckb_std::{ckb_constants::Source};
+
+use crate::error::*;
+use crate::helper::{
+    get_script_hash_cell_count,
+    has_input_lock_hash,
+    load_time_index_state_args,
+    load_time_index_state,
+    time_info_update_check,
+};
+
+pub fn update(script_hash: [u8; 32]) -> Result<(), Error> {
+    //should only one time index state cell in input and output
+    if get_script_hash_cell_count(script_hash, Source::Input) != 1 {
+        return Err(Error::InvalidTimeIndexStateInput);
+    }
+    if get_script_hash_cell_count(script_hash, Source::Output) != 1 {
+        return Err(Error::InvalidTimeIndexStateOutput);
+    }
+
+    //only operator can update time index state cell when operator lock hash is set in args
+    let state_args = load_time_index_state_args()?;
+    if let Some(operator_lock_hash) = state_args.operator_lock_hash {
+        if !has_input_lock_hash(operator_lock_hash) {
+            return Err(Error::InvalidOperatorAuthorization);
+        }
+    }
+
+    let last_state = load_time_index_state(Source::GroupInput)?;
+    let current_state = load_time_index_state(Source::GroupOutput)?;
+
+    //N cannot be changed on update
+    if current_state.ring_size != last_state.ring_size {
+        return Err(Error::InvalidRingSize);
+    }
+
+    //index should move to the next time info cell of the ring, index + 1 cannot overflow since index < N
+    if current_state.index != (last_state.index + 1) % last_state.ring_size {
+        return Err(Error::InvalidTimeIndex);
+    }
+
+    //time info cell of the new index should move together when time info family is set in args
+    if let Some(time_info_family) = &state_args.time_info_family {
+        time_info_update_check(time_info_family, current_state.index)?;
+    }
+    Ok(())
+}
@@ -1,8 +1,15 @@
+use core::convert::TryFrom;
+
 use ckb_std::{ckb_constants::Source};
-use ckb_std::high_level::load_script;
+use ckb_std::ckb_types::{bytes::Bytes, prelude::*};
+use ckb_std::high_level::{load_input_out_point, load_script, QueryIter};
+
+use time_info_types::args::{parse_time_info_args, TIME_INFO_ARGS_OUT_POINT_LEN};
+use time_info_types::chain::GENESIS_PREV_DATA_HASH;
+use time_info_types::data::{TimeInfoData, TIME_INFO_CELL_DATA_VERSION};
 
 use crate::error::*;
-use crate::helper::{get_script_hash_cell_count, TIME_INFO_CELL_DATA_LEN, TIME_INFO_CELL_DATA_N};
+use crate::helper::{get_script_hash_cell_count, header_dep_block_check, load_family_cells};
 
 pub fn create(script_hash: [u8; 32]) -> Result<(), Error> {
     //should only one time info cell in output
@@ -11,20 +18,84 @@ pub fn create(script_hash: [u8; 32]) -> Result<(), Error> {
     }
 
     let script = load_script()?;
-    //scrip args cannot empty (txHash + index)
-    if script.args().len() != 36 {
-        return Err(Error::InvalidArgument);
+    //scrip args cannot empty (txHash + index), optional fields should be valid
+    let script_args: Bytes = script.args().unpack();
+    let time_info_args = parse_time_info_args(&script_args)?;
+
+    //the out point in args should be consumed by this transaction, like type id
+    let args_out_point = &script_args[..TIME_INFO_ARGS_OUT_POINT_LEN];
+    if !QueryIter::new(load_input_out_point, Source::Input).
+        any(|out_point| out_point.as_slice() == args_out_point) {
+        return Err(Error::InvalidArgsOutPoint);
     }
 
+    //new time info cell should use the latest cell data layout
     let output_cell_data = crate::helper::load_cell_data(script_hash, Source::Output)?;
-    if output_cell_data.len() != TIME_INFO_CELL_DATA_LEN as usize {
+    let output_data = TimeInfoData::try_from(output_cell_data.as_slice())?;
+    if output_data.version != TIME_INFO_CELL_DATA_VERSION {
         return Err(Error::InvalidCellData);
     }
 
-    //time index cannot large then TIME_INFO_CELL_DATA_N
-    let time_index = output_cell_data[0];
-    if time_index >= TIME_INFO_CELL_DATA_N {
+    //sequence counts updates, so it starts at zero
+    if output_data.sequence != Some(0) {
+        return Err(Error::InvalidSequence);
+    }
+
+    //hash chain starts from new time info cell
+    if output_data.prev_data_hash != Some(GENESIS_PREV_DATA_HASH) {
+        return Err(Error::InvalidPrevDataHash);
+    }
+
+    //oracle entries are attested on update, so new time info cell has none
+    if output_data.oracle_entries.is_some() {
+        return Err(Error::InvalidOracleEntries);
+    }
+
+    //only admin can pause existing time info cell, so new time info cell is active
+    if output_data.paused {
+        return Err(Error::TimeInfoPaused);
+    }
+
+    //block number and epoch in cell data should be attested with timestamp by the header dep
+    if let Some(header_dep_block) = &output_data.header_dep_block {
+        header_dep_block_check(header_dep_block, output_data.timestamp, time_info_args.header_dep_tolerance)?;
+    }
+
+    //time index cannot large then ring size, which is TIME_INFO_CELL_DATA_N by default
+    let time_index = output_data.index;
+    if time_index >= time_info_args.ring_size() {
         return Err(Error::InvalidTimeIndex);
     }
+
+    //time index should be bound to the ring index when ring index is set in args
+    if let Some(ring_index) = time_info_args.ring_index {
+        if time_index != ring_index {
+            return Err(Error::InvalidTimeIndex);
+        }
+    }
+
+    //out point in args can only be consumed once, so all time info cells of a family are created
+    //in this transaction, every time index should be used only once in the family
+    let family_cells = load_family_cells(&script)?;
+    if family_cells.iter().filter(|(_, family_time_index)| *family_time_index == time_index).count() != 1 {
+        return Err(Error::DuplicateTimeIndex);
+    }
+
+    //with ring index in args, the whole ring is created in genesis transaction, which should have
+    //time index 0..N-1 exactly once, and all cells share the same args except the ring index
+    if time_info_args.ring_index.is_some() {
+        if family_cells.len() != time_info_args.ring_size() as usize {
+            return Err(Error::InvalidRingGenesis);
+        }
+        for (mut family_args, family_time_index) in family_cells {
+            if family_args.ring_index != Some(family_time_index) {
+                return Err(Error::InvalidRingGenesis);
+            }
+            family_args.ring_index = time_info_args.ring_index;
+            if family_args != time_info_args {
+                return Err(Error::InvalidRingGenesis);
+            }
+        }
+    }
     Ok(())
-}
\ No newline at end of file
+}
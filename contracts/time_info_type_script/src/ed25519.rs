@@ -0,0 +1,420 @@
+use core::convert::TryFrom;
+use core::ops::{Add, Mul, Neg, Sub};
+
+use sha2::{Digest, Sha512};
+use time_info_types::chain::ckb_blake2b_256;
+
+use crate::error::Error;
+
+//ed25519 oracle puts pubkey | signature in the witness, and the oracle key in args is the blake160 of pubkey,
+//the signature is verified as RFC 8032, variable time is fine since everything verified is public
+const PUBKEY_LEN: usize = 32;
+const SIGNATURE_LEN: usize = 64;
+const PUBKEY_HASH_LEN: usize = 20;
+
+const LOW_51_BIT_MASK: u64 = (1 << 51) - 1;
+
+//element of GF(2^255 - 19) in radix 2^51, limbs are kept below 2^52 after every operation
+#[derive(Clone, Copy)]
+struct Fe([u64; 5]);
+
+const FE_ZERO: Fe = Fe([0, 0, 0, 0, 0]);
+const FE_ONE: Fe = Fe([1, 0, 0, 0, 0]);
+//d = -121665 / 121666
+const EDWARDS_D: Fe = Fe([929955233495203, 466365720129213, 1662059464998953, 2033849074728123, 1442794654840575]);
+const EDWARDS_D2: Fe = Fe([1859910466990425, 932731440258426, 1072319116312658, 1815898335770999, 633789495995903]);
+const SQRT_M1: Fe = Fe([1718705420411056, 234908883556509, 2233514472574048, 2117202627021982, 765476049583133]);
+//base point B, whose y is 4 / 5 and x is even
+const BASE_X: Fe = Fe([1738742601995546, 1146398526822698, 2070867633025821, 562264141797630, 587772402128613]);
+const BASE_Y: Fe = Fe([1801439850948184, 1351079888211148, 450359962737049, 900719925474099, 1801439850948198]);
+const BASE_T: Fe = Fe([1841354044333475, 16398895984059, 755974180946558, 900171276175154, 1821297809914039]);
+
+//L = 2^252 + 27742317777372353535851937790883648493, the order of base point, in little endian bytes
+const L: [i64; 32] = [
+    0xed, 0xd3, 0xf5, 0x5c, 0x1a, 0x63, 0x12, 0x58, 0xd6, 0x9c, 0xf7, 0xa2, 0xde, 0xf9, 0xde, 0x14,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x10,
+];
+
+fn load_u64(bytes: &[u8]) -> u64 {
+    let mut buf = [0_u8; 8];
+    buf.copy_from_slice(&bytes[..8]);
+    u64::from_le_bytes(buf)
+}
+
+impl Fe {
+    //the highest bit is ignored
+    fn from_bytes(bytes: &[u8; 32]) -> Fe {
+        Fe([
+            load_u64(&bytes[0..]) & LOW_51_BIT_MASK,
+            (load_u64(&bytes[6..]) >> 3) & LOW_51_BIT_MASK,
+            (load_u64(&bytes[12..]) >> 6) & LOW_51_BIT_MASK,
+            (load_u64(&bytes[19..]) >> 1) & LOW_51_BIT_MASK,
+            (load_u64(&bytes[24..]) >> 12) & LOW_51_BIT_MASK,
+        ])
+    }
+
+    //canonical encoding, which is less than 2^255 - 19
+    fn to_bytes(self) -> [u8; 32] {
+        let mut limbs = Fe::reduce(self.0).0;
+        //q is 1 when the value is at least 2^255 - 19
+        let mut q = (limbs[0] + 19) >> 51;
+        for limb in &limbs[1..] {
+            q = (limb + q) >> 51;
+        }
+        limbs[0] += 19 * q;
+        for i in 0..4 {
+            limbs[i + 1] += limbs[i] >> 51;
+            limbs[i] &= LOW_51_BIT_MASK;
+        }
+        limbs[4] &= LOW_51_BIT_MASK;
+
+        let mut bytes = [0_u8; 32];
+        let mut acc: u128 = 0;
+        let mut acc_bits = 0;
+        let mut position = 0;
+        for limb in &limbs {
+            acc |= (*limb as u128) << acc_bits;
+            acc_bits += 51;
+            while acc_bits >= 8 {
+                bytes[position] = acc as u8;
+                acc >>= 8;
+                acc_bits -= 8;
+                position += 1;
+            }
+        }
+        bytes[position] = acc as u8;
+        bytes
+    }
+
+    //carry every limb into the next one, limbs of result are below 2^51 + 2^18
+    fn reduce(mut limbs: [u64; 5]) -> Fe {
+        let carry: [u64; 5] = [
+            limbs[0] >> 51,
+            limbs[1] >> 51,
+            limbs[2] >> 51,
+            limbs[3] >> 51,
+            limbs[4] >> 51,
+        ];
+        for limb in limbs.iter_mut() {
+            *limb &= LOW_51_BIT_MASK;
+        }
+        limbs[0] += carry[4] * 19;
+        limbs[1] += carry[0];
+        limbs[2] += carry[1];
+        limbs[3] += carry[2];
+        limbs[4] += carry[3];
+        Fe(limbs)
+    }
+
+    fn square(&self) -> Fe {
+        *self * *self
+    }
+
+    fn pow2k(&self, k: u32) -> Fe {
+        let mut result = *self;
+        for _ in 0..k {
+            result = result.square();
+        }
+        result
+    }
+
+    //returns (self^(2^250 - 1), self^11)
+    fn pow22501(&self) -> (Fe, Fe) {
+        let t0 = self.square();
+        let t1 = t0.pow2k(2);
+        let t2 = *self * t1;
+        let t3 = t0 * t2;
+        let t4 = t3.square();
+        let t5 = t2 * t4;
+        let t7 = t5.pow2k(5) * t5;
+        let t9 = t7.pow2k(10) * t7;
+        let t11 = t9.pow2k(20) * t9;
+        let t13 = t11.pow2k(10) * t7;
+        let t15 = t13.pow2k(50) * t13;
+        let t17 = t15.pow2k(100) * t15;
+        let t19 = t17.pow2k(50) * t13;
+        (t19, t3)
+    }
+
+    //self^(p - 2)
+    fn invert(&self) -> Fe {
+        let (t19, t3) = self.pow22501();
+        t19.pow2k(5) * t3
+    }
+
+    //self^((p - 5) / 8)
+    fn pow_p58(&self) -> Fe {
+        let (t19, _) = self.pow22501();
+        t19.pow2k(2) * *self
+    }
+
+    fn is_negative(&self) -> bool {
+        self.to_bytes()[0] & 1 == 1
+    }
+
+    fn is_zero(&self) -> bool {
+        self.to_bytes() == [0_u8; 32]
+    }
+}
+
+impl PartialEq for Fe {
+    fn eq(&self, other: &Fe) -> bool {
+        self.to_bytes() == other.to_bytes()
+    }
+}
+
+impl Add for Fe {
+    type Output = Fe;
+
+    fn add(self, other: Fe) -> Fe {
+        let mut limbs = self.0;
+        for (limb, other_limb) in limbs.iter_mut().zip(&other.0) {
+            *limb += other_limb;
+        }
+        Fe::reduce(limbs)
+    }
+}
+
+impl Sub for Fe {
+    type Output = Fe;
+
+    //add 16p first, so no limb underflows
+    fn sub(self, other: Fe) -> Fe {
+        Fe::reduce([
+            (self.0[0] + 36028797018963664) - other.0[0],
+            (self.0[1] + 36028797018963952) - other.0[1],
+            (self.0[2] + 36028797018963952) - other.0[2],
+            (self.0[3] + 36028797018963952) - other.0[3],
+            (self.0[4] + 36028797018963952) - other.0[4],
+        ])
+    }
+}
+
+impl Neg for Fe {
+    type Output = Fe;
+
+    fn neg(self) -> Fe {
+        FE_ZERO - self
+    }
+}
+
+impl Mul for Fe {
+    type Output = Fe;
+
+    fn mul(self, other: Fe) -> Fe {
+        fn m(x: u64, y: u64) -> u128 {
+            x as u128 * y as u128
+        }
+        let a = &self.0;
+        let b = &other.0;
+        //2^255 = 19 (mod p), so the limbs above 2^255 fold back multiplied by 19
+        let b1_19 = b[1] * 19;
+        let b2_19 = b[2] * 19;
+        let b3_19 = b[3] * 19;
+        let b4_19 = b[4] * 19;
+        let c0 = m(a[0], b[0]) + m(a[4], b1_19) + m(a[3], b2_19) + m(a[2], b3_19) + m(a[1], b4_19);
+        let mut c1 = m(a[1], b[0]) + m(a[0], b[1]) + m(a[4], b2_19) + m(a[3], b3_19) + m(a[2], b4_19);
+        let mut c2 = m(a[2], b[0]) + m(a[1], b[1]) + m(a[0], b[2]) + m(a[4], b3_19) + m(a[3], b4_19);
+        let mut c3 = m(a[3], b[0]) + m(a[2], b[1]) + m(a[1], b[2]) + m(a[0], b[3]) + m(a[4], b4_19);
+        let mut c4 = m(a[4], b[0]) + m(a[3], b[1]) + m(a[2], b[2]) + m(a[1], b[3]) + m(a[0], b[4]);
+
+        c1 += c0 >> 51;
+        c2 += c1 >> 51;
+        c3 += c2 >> 51;
+        c4 += c3 >> 51;
+        let mut limbs = [
+            c0 as u64 & LOW_51_BIT_MASK,
+            c1 as u64 & LOW_51_BIT_MASK,
+            c2 as u64 & LOW_51_BIT_MASK,
+            c3 as u64 & LOW_51_BIT_MASK,
+            c4 as u64 & LOW_51_BIT_MASK,
+        ];
+        limbs[0] += (c4 >> 51) as u64 * 19;
+        limbs[1] += limbs[0] >> 51;
+        limbs[0] &= LOW_51_BIT_MASK;
+        Fe(limbs)
+    }
+}
+
+//point of edwards25519 in extended coordinates, x = X / Z, y = Y / Z and x * y = T / Z
+#[derive(Clone, Copy)]
+struct Point {
+    x: Fe,
+    y: Fe,
+    z: Fe,
+    t: Fe,
+}
+
+const IDENTITY: Point = Point { x: FE_ZERO, y: FE_ONE, z: FE_ONE, t: FE_ZERO };
+const BASE_POINT: Point = Point { x: BASE_X, y: BASE_Y, z: FE_ONE, t: BASE_T };
+
+impl Point {
+    //y with the sign of x in the highest bit, y should be canonical
+    fn decompress(bytes: &[u8; 32]) -> Option<Point> {
+        let y = Fe::from_bytes(bytes);
+        let mut canonical_y = *bytes;
+        canonical_y[31] &= 0x7f;
+        if y.to_bytes() != canonical_y {
+            return None;
+        }
+
+        //x^2 = (y^2 - 1) / (d * y^2 + 1) = u / v, and x = u * v^3 * (u * v^7)^((p - 5) / 8)
+        let yy = y.square();
+        let u = yy - FE_ONE;
+        let v = yy * EDWARDS_D + FE_ONE;
+        let v3 = v.square() * v;
+        let v7 = v3.square() * v;
+        let mut x = u * v3 * (u * v7).pow_p58();
+        let vxx = v * x.square();
+        if vxx != u {
+            if vxx != -u {
+                return None;
+            }
+            x = x * SQRT_M1;
+        }
+
+        let x_is_negative = bytes[31] >> 7 == 1;
+        if x.is_zero() && x_is_negative {
+            return None;
+        }
+        if x.is_negative() != x_is_negative {
+            x = -x;
+        }
+        Some(Point { x, y, z: FE_ONE, t: x * y })
+    }
+
+    fn compress(&self) -> [u8; 32] {
+        let z_inv = self.z.invert();
+        let mut bytes = (self.y * z_inv).to_bytes();
+        if (self.x * z_inv).is_negative() {
+            bytes[31] |= 0x80;
+        }
+        bytes
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    //unified addition of twisted edwards curve with a = -1, which doubles as well
+    fn add(self, other: Point) -> Point {
+        let a = (self.y - self.x) * (other.y - other.x);
+        let b = (self.y + self.x) * (other.y + other.x);
+        let c = self.t * EDWARDS_D2 * other.t;
+        let d = self.z * other.z;
+        let d = d + d;
+        let e = b - a;
+        let f = d - c;
+        let g = d + c;
+        let h = b + a;
+        Point { x: e * f, y: g * h, z: f * g, t: e * h }
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point { x: -self.x, y: self.y, z: self.z, t: -self.t }
+    }
+}
+
+//[s]B + [h]A by one pass of doublings
+fn double_scalar_mul_base(s: &[u8; 32], h: &[u8; 32], a: &Point) -> Point {
+    let base_plus_a = BASE_POINT + *a;
+    let mut result = IDENTITY;
+    for i in (0..256).rev() {
+        result = result + result;
+        let s_bit = (s[i >> 3] >> (i & 7)) & 1;
+        let h_bit = (h[i >> 3] >> (i & 7)) & 1;
+        match (s_bit, h_bit) {
+            (1, 1) => result = result + base_plus_a,
+            (1, 0) => result = result + BASE_POINT,
+            (0, 1) => result = result + *a,
+            _ => (),
+        }
+    }
+    result
+}
+
+//reduce 512 bits little endian number modulo L
+fn reduce_scalar(hash: &[u8]) -> [u8; 32] {
+    let mut x = [0_i64; 64];
+    for (limb, byte) in x.iter_mut().zip(hash) {
+        *limb = *byte as i64;
+    }
+    for i in (32..64).rev() {
+        let mut carry = 0;
+        for j in (i - 32)..(i - 12) {
+            x[j] += carry - 16 * x[i] * L[j - (i - 32)];
+            carry = (x[j] + 128) >> 8;
+            x[j] -= carry << 8;
+        }
+        x[i - 12] += carry;
+        x[i] = 0;
+    }
+    let mut carry = 0;
+    for j in 0..32 {
+        x[j] += carry - (x[31] >> 4) * L[j];
+        carry = x[j] >> 8;
+        x[j] &= 255;
+    }
+    for (limb, l) in x.iter_mut().zip(&L) {
+        *limb -= carry * l;
+    }
+    let mut scalar = [0_u8; 32];
+    for i in 0..32 {
+        x[i + 1] += x[i] >> 8;
+        scalar[i] = (x[i] & 255) as u8;
+    }
+    scalar
+}
+
+//s should be less than L, so a signature cannot be malleated
+fn is_canonical_scalar(s: &[u8; 32]) -> bool {
+    for (byte, l) in s.iter().zip(&L).rev() {
+        if *byte as i64 != *l {
+            return (*byte as i64) < *l;
+        }
+    }
+    false
+}
+
+//R = [s]B - [SHA512(R | A | message)]A
+fn verify(pubkey: &[u8; PUBKEY_LEN], message: &[u8], signature: &[u8; SIGNATURE_LEN]) -> bool {
+    let mut r = [0_u8; 32];
+    r.copy_from_slice(&signature[..32]);
+    let mut s = [0_u8; 32];
+    s.copy_from_slice(&signature[32..]);
+    if !is_canonical_scalar(&s) {
+        return false;
+    }
+    let a = match Point::decompress(pubkey) {
+        Some(a) => a,
+        None => return false,
+    };
+
+    let mut hasher = Sha512::new();
+    hasher.input(r);
+    hasher.input(&pubkey[..]);
+    hasher.input(message);
+    let h = reduce_scalar(&hasher.result());
+    double_scalar_mul_base(&s, &h, &-a).compress() == r
+}
+
+//verify witness of ed25519 oracle and return the blake160 of its pubkey, which is compared with
+//the oracle key like the pubkey hash recovered from secp256k1 signature
+pub fn recover_pubkey_hash(witness: &[u8], message: &[u8]) -> Result<[u8; PUBKEY_HASH_LEN], Error> {
+    if witness.len() != PUBKEY_LEN + SIGNATURE_LEN {
+        return Err(Error::InvalidOracleSignature);
+    }
+    let (pubkey, signature) = witness.split_at(PUBKEY_LEN);
+    let pubkey = <[u8; PUBKEY_LEN]>::try_from(pubkey).map_err(|_| Error::InvalidOracleSignature)?;
+    let signature = <[u8; SIGNATURE_LEN]>::try_from(signature).map_err(|_| Error::InvalidOracleSignature)?;
+    if !verify(&pubkey, message, &signature) {
+        return Err(Error::InvalidOracleSignature);
+    }
+    let mut pubkey_hash = [0_u8; PUBKEY_HASH_LEN];
+    pubkey_hash.copy_from_slice(&ckb_blake2b_256(&pubkey)[..PUBKEY_HASH_LEN]);
+    Ok(pubkey_hash)
+}
@@ -4,7 +4,9 @@ use ckb_std::ckb_constants::Source;
 use ckb_std::high_level::{load_cell_type_hash, load_script_hash, QueryIter};
 
 use crate::create::create;
+use crate::destroy::destroy;
 use crate::error::Error;
+use crate::helper::get_script_hash_cell_count;
 use crate::update::update;
 
 pub fn main() -> Result<(), Error> {
@@ -16,7 +18,11 @@ pub fn main() -> Result<(), Error> {
                 None => return false
             }
         }) {
-        update(script_hash)
+        if get_script_hash_cell_count(script_hash, Source::Output) == 0 {
+            destroy()
+        } else {
+            update(script_hash)
+        }
     } else {
         create(script_hash)
     }
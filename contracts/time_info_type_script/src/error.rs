@@ -1,19 +1,47 @@
 use ckb_std::error::SysError;
+use time_info_types::error::*;
 
-/// Error
+/// Error, the codes are defined in time_info_types
 #[repr(i8)]
 pub enum Error {
-    IndexOutOfBound = 50,
-    ItemMissing,
-    LengthNotEnough,
-    Encoding,
-    InvalidArgument,
-    InvalidCellData,
-    InvalidTimeInfoInput,
-    InvalidTImeInfoOutput,
-    InvalidTimeSince,
-    InvalidTimestamp,
-    InvalidTimeIndex,
+    IndexOutOfBound = ERROR_INDEX_OUT_OF_BOUND,
+    ItemMissing = ERROR_ITEM_MISSING,
+    LengthNotEnough = ERROR_LENGTH_NOT_ENOUGH,
+    Encoding = ERROR_ENCODING,
+    InvalidArgument = ERROR_INVALID_ARGUMENT,
+    InvalidCellData = ERROR_INVALID_CELL_DATA,
+    InvalidTimeInfoInput = ERROR_INVALID_TIME_INFO_INPUT,
+    InvalidTImeInfoOutput = ERROR_INVALID_TIME_INFO_OUTPUT,
+    InvalidTimeSince = ERROR_INVALID_TIME_SINCE,
+    InvalidTimestamp = ERROR_INVALID_TIMESTAMP,
+    InvalidTimeIndex = ERROR_INVALID_TIME_INDEX,
+    InvalidArgsOutPoint = ERROR_INVALID_ARGS_OUT_POINT,
+    DestroyNotAllowed = ERROR_DESTROY_NOT_ALLOWED,
+    InvalidAdminAuthorization = ERROR_INVALID_ADMIN_AUTHORIZATION,
+    InvalidOperatorAuthorization = ERROR_INVALID_OPERATOR_AUTHORIZATION,
+    InvalidUpdateInterval = ERROR_INVALID_UPDATE_INTERVAL,
+    InvalidTimestampStep = ERROR_INVALID_TIMESTAMP_STEP,
+    InvalidCellDataVersion = ERROR_INVALID_CELL_DATA_VERSION,
+    InvalidHeaderDep = ERROR_INVALID_HEADER_DEP,
+    InvalidHeaderDepTimestamp = ERROR_INVALID_HEADER_DEP_TIMESTAMP,
+    InvalidSinceFlags = ERROR_INVALID_SINCE_FLAGS,
+    RelativeSince = ERROR_RELATIVE_SINCE,
+    DuplicateTimeIndex = ERROR_DUPLICATE_TIME_INDEX,
+    InvalidRingGenesis = ERROR_INVALID_RING_GENESIS,
+    InvalidTimeIndexState = ERROR_INVALID_TIME_INDEX_STATE,
+    UnknownSysError = ERROR_UNKNOWN_SYS_ERROR,
+    InvalidCellLock = ERROR_INVALID_CELL_LOCK,
+    InvalidCellCapacity = ERROR_INVALID_CELL_CAPACITY,
+    InvalidOracleLib = ERROR_INVALID_ORACLE_LIB,
+    InvalidOracleWitness = ERROR_INVALID_ORACLE_WITNESS,
+    InvalidOracleSignature = ERROR_INVALID_ORACLE_SIGNATURE,
+    InvalidOracleQuorum = ERROR_INVALID_ORACLE_QUORUM,
+    InvalidOracleTimestamp = ERROR_INVALID_ORACLE_TIMESTAMP,
+    InvalidOracleEntries = ERROR_INVALID_ORACLE_ENTRIES,
+    InvalidSequence = ERROR_INVALID_SEQUENCE,
+    InvalidPrevDataHash = ERROR_INVALID_PREV_DATA_HASH,
+    InvalidHeaderDepBlock = ERROR_INVALID_HEADER_DEP_BLOCK,
+    TimeInfoPaused = ERROR_TIME_INFO_PAUSED,
 }
 
 impl From<SysError> for Error {
@@ -24,8 +52,22 @@ impl From<SysError> for Error {
             ItemMissing => Self::ItemMissing,
             LengthNotEnough(_) => Self::LengthNotEnough,
             Encoding => Self::Encoding,
-            Unknown(err_code) => panic!("unexpected sys error {}", err_code),
+            Unknown(_) => Self::UnknownSysError,
         }
     }
 }
 
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Self {
+        match err {
+            ParseError::InvalidArgument => Self::InvalidArgument,
+            ParseError::EmptyCellData
+            | ParseError::UnknownCellDataVersion(_)
+            | ParseError::InvalidCellDataLength
+            | ParseError::InvalidCellDataFlags => Self::InvalidCellData,
+            ParseError::InvalidOracleWitness => Self::InvalidOracleWitness,
+            ParseError::InvalidOracleEntries => Self::InvalidOracleEntries,
+            ParseError::TimeInfoPaused => Self::TimeInfoPaused,
+        }
+    }
+}
@@ -1,12 +1,39 @@
 use alloc::{vec::Vec};
+use core::convert::TryFrom;
 
-use ckb_std::{ckb_constants::Source, high_level::*};
-use ckb_std::ckb_types::{bytes::Bytes, prelude::*};
+use ckb_std::{ckb_constants::Source, error::SysError, high_level::*};
+use ckb_std::ckb_types::{bytes::Bytes, packed::{Header, Script}, prelude::*};
+use ckb_std::since::{LockValue, Since};
 
+use ckb_std::dynamic_loading::CKBDLContext;
+use time_info_types::args::{
+    parse_time_info_args,
+    OracleKey,
+    OracleQuorum,
+    TimeInfoArgs,
+    ORACLE_KEY_TYPE_ED25519,
+    ORACLE_KEY_TYPE_SECP256K1,
+    TIME_INFO_ARGS_OUT_POINT_LEN,
+};
+use time_info_types::chain::ckb_blake2b_256;
+use time_info_types::data::{HeaderDepBlock, TimeInfoData, CELL_DATA_MIGRATIONS};
+use time_info_types::oracle::{encode_oracle_message, median_timestamp, parse_oracle_attestations, OracleEntries, OracleEntry};
+use time_info_types::state::TimeIndexStateData;
+
+use crate::ed25519;
 use crate::error::Error;
+use crate::secp256k1::LibSecp256k1;
 
-pub const TIME_INFO_CELL_DATA_LEN: u8 = 5;
-pub const TIME_INFO_CELL_DATA_N: u8 = 12;
+pub fn load_time_info_args() -> Result<TimeInfoArgs, Error> {
+    let script = load_script()?;
+    let script_args: Bytes = script.args().unpack();
+    Ok(parse_time_info_args(&script_args)?)
+}
+
+pub fn has_input_lock_hash(lock_hash: [u8; 32]) -> bool {
+    QueryIter::new(load_cell_lock_hash, Source::Input).
+        any(|input_lock_hash| input_lock_hash == lock_hash)
+}
 
 pub fn get_script_hash_cell_count(script_hash: [u8; 32], source: Source) -> usize {
     QueryIter::new(load_cell_type_hash, source).
@@ -44,18 +71,85 @@ pub fn load_cell_data(script_hash: [u8; 32], source: Source) -> Result<Vec<u8>,
     }
 }
 
-pub fn get_timestamp_from_cell_data(cell_data: &Vec<u8>) -> u32 {
-    let mut buf = [0_u8; 4];
-    buf.copy_from_slice(&(cell_data.as_slice()[1..]));
-    u32::from_be_bytes(buf)
+//time info cells which are created with the same out point in args are in the same family,
+//return the args and time index of time info cells in output of the family of script
+pub fn load_family_cells(script: &Script) -> Result<Vec<(TimeInfoArgs, u8)>, Error> {
+    let script_args: Bytes = script.args().unpack();
+    let family_id = &script_args[..TIME_INFO_ARGS_OUT_POINT_LEN];
+
+    let mut family_cells = Vec::new();
+    for (index, type_script) in QueryIter::new(load_cell_type, Source::Output).enumerate() {
+        let type_script = match type_script {
+            Some(type_script) => type_script,
+            None => continue,
+        };
+        let type_script_args: Bytes = type_script.args().unpack();
+        if type_script.code_hash().as_slice() != script.code_hash().as_slice()
+            || type_script.hash_type().as_slice() != script.hash_type().as_slice()
+            || type_script_args.len() < TIME_INFO_ARGS_OUT_POINT_LEN
+            || &type_script_args[..TIME_INFO_ARGS_OUT_POINT_LEN] != family_id {
+            continue;
+        }
+        let cell_data = ckb_std::high_level::load_cell_data(index, Source::Output)?;
+        family_cells.push((
+            parse_time_info_args(&type_script_args)?,
+            TimeInfoData::try_from(cell_data.as_slice())?.index,
+        ));
+    }
+    Ok(family_cells)
+}
+
+pub fn cell_data_version_check(last_version: u8, current_version: u8) -> Result<(), Error> {
+    if last_version != current_version && !CELL_DATA_MIGRATIONS.contains(&(last_version, current_version)) {
+        return Err(Error::InvalidCellDataVersion);
+    }
+    Ok(())
+}
+
+//sequence increases by exactly one on every update, and a cell migrated from a layout
+//without sequence counts as zero updates before the migration
+pub fn sequence_check(last_sequence: Option<u64>, current_sequence: Option<u64>) -> Result<(), Error> {
+    match (last_sequence, current_sequence) {
+        (_, Some(current_sequence)) if last_sequence.unwrap_or(0).checked_add(1) == Some(current_sequence) => Ok(()),
+        (None, None) => Ok(()),
+        _ => Err(Error::InvalidSequence),
+    }
+}
+
+//pausing or resuming needs admin authorization and keeps every field except the paused flag,
+//sequence and previous data hash as they are, the version may change by a registered migration
+//which is checked before, so a legacy cell can be paused in the same update which migrates it
+pub fn pause_toggle_check(input_data: &TimeInfoData, output_data: &TimeInfoData, admin_lock_hash: Option<[u8; 32]>) -> Result<(), Error> {
+    match admin_lock_hash {
+        Some(admin_lock_hash) if has_input_lock_hash(admin_lock_hash) => (),
+        _ => return Err(Error::InvalidAdminAuthorization),
+    }
+    let expected_data = TimeInfoData {
+        version: output_data.version,
+        sequence: output_data.sequence,
+        prev_data_hash: output_data.prev_data_hash,
+        paused: output_data.paused,
+        ..*input_data
+    };
+    if *output_data != expected_data {
+        return Err(Error::InvalidCellData);
+    }
+    Ok(())
+}
+
+//previous data hash chains cell data of every update, it is none only for the layouts without it
+pub fn prev_data_hash_check(last_cell_data: &[u8], prev_data_hash: Option<[u8; 32]>) -> Result<(), Error> {
+    match prev_data_hash {
+        Some(prev_data_hash) if prev_data_hash != ckb_blake2b_256(last_cell_data) => Err(Error::InvalidPrevDataHash),
+        _ => Ok(()),
+    }
 }
 
-pub fn cell_args_check(script_hash: [u8; 32]) -> Result<(), Error> {
+pub fn cell_args_check(script_hash: [u8; 32]) -> Result<TimeInfoArgs, Error> {
     let script = load_script()?;
     let script_args: Bytes = script.args().unpack();
-    if script_args.is_empty() {
-        return Err(Error::InvalidArgument);
-    }
+    //legacy args only has out point, optional fields are appended after it
+    let time_info_args = parse_time_info_args(&script_args)?;
 
     let cell_index = match get_position_of_cell_with_type_script(script_hash, Source::Input) {
         Some(position) => position,
@@ -71,21 +165,254 @@ pub fn cell_args_check(script_hash: [u8; 32]) -> Result<(), Error> {
     if input_script_args[..] != script_args[..] {
         return Err(Error::InvalidArgument);
     }
-    Ok(())
+    Ok(time_info_args)
 }
 
-pub fn timestamp_check(last_timestamp: u32, current_timestamp: u32) -> Result<(), Error> {
+pub fn timestamp_check(last_timestamp: u64, current_timestamp: u64) -> Result<(), Error> {
     if current_timestamp <= last_timestamp {
         return Err(Error::InvalidTimestamp);
     }
     Ok(())
 }
 
-pub fn input_cell_since_check(timestamp: u32) -> Result<(), Error> {
-    let since_base: u64 = 1 << 62;
-    if QueryIter::new(load_input_since, Source::GroupInput).
-        any(|since| since != since_base + timestamp as u64) {
+pub fn update_interval_check(last_timestamp: u64, current_timestamp: u64, min_interval: u32) -> Result<(), Error> {
+    if current_timestamp.saturating_sub(last_timestamp) < min_interval as u64 {
+        return Err(Error::InvalidUpdateInterval);
+    }
+    Ok(())
+}
+
+pub fn timestamp_step_check(last_timestamp: u64, current_timestamp: u64, max_step: u32) -> Result<(), Error> {
+    if current_timestamp.saturating_sub(last_timestamp) > max_step as u64 {
+        return Err(Error::InvalidTimestampStep);
+    }
+    Ok(())
+}
+
+//the first header dep is the anchor of timestamp
+fn load_anchor_header() -> Result<Header, Error> {
+    match load_header(0, Source::HeaderDep) {
+        Ok(header) => Ok(header),
+        Err(SysError::IndexOutOfBound) => Err(Error::InvalidHeaderDep),
+        Err(sys_err) => Err(Error::from(sys_err)),
+    }
+}
+
+fn header_timestamp_check(header: &Header, timestamp: u64, tolerance: u32) -> Result<(), Error> {
+    //timestamp of header is in milliseconds
+    let header_timestamp: u64 = header.raw().timestamp().unpack();
+    let header_timestamp = header_timestamp / 1000;
+    let diff = if timestamp > header_timestamp {
+        timestamp - header_timestamp
+    } else {
+        header_timestamp - timestamp
+    };
+    if diff > tolerance as u64 {
+        return Err(Error::InvalidHeaderDepTimestamp);
+    }
+    Ok(())
+}
+
+pub fn header_dep_timestamp_check(timestamp: u64, tolerance: u32) -> Result<(), Error> {
+    header_timestamp_check(&load_anchor_header()?, timestamp, tolerance)
+}
+
+//block number and epoch in cell data should be the ones of the first header dep
+//block number and epoch are attested together with timestamp only when timestamp is anchored to the same
+//header dep, so header dep tolerance should be set in args
+pub fn header_dep_block_check(header_dep_block: &HeaderDepBlock, timestamp: u64, tolerance: Option<u32>) -> Result<(), Error> {
+    let tolerance = match tolerance {
+        Some(tolerance) => tolerance,
+        None => return Err(Error::InvalidHeaderDepBlock),
+    };
+    let header = load_anchor_header()?;
+    header_timestamp_check(&header, timestamp, tolerance)?;
+
+    let block_number: u64 = header.raw().number().unpack();
+    let epoch: u64 = header.raw().epoch().unpack();
+    if header_dep_block.block_number != block_number || header_dep_block.epoch != epoch {
+        return Err(Error::InvalidHeaderDepBlock);
+    }
+    Ok(())
+}
+
+//time index state cell should be updated in the same transaction, and point to the time index
+//of updated time info cell
+pub fn time_index_state_check(state_type_hash: [u8; 32], time_index: u8, ring_size: u8) -> Result<(), Error> {
+    if get_script_hash_cell_count(state_type_hash, Source::Input) != 1
+        || get_script_hash_cell_count(state_type_hash, Source::Output) != 1 {
+        return Err(Error::InvalidTimeIndexState);
+    }
+    let state_cell_data = load_cell_data(state_type_hash, Source::Output)?;
+    let state_data = match TimeIndexStateData::try_from(state_cell_data.as_slice()) {
+        Ok(state_data) => state_data,
+        Err(_) => return Err(Error::InvalidTimeIndexState),
+    };
+    if state_data.index != time_index || state_data.ring_size != ring_size {
+        return Err(Error::InvalidTimeIndexState);
+    }
+    Ok(())
+}
+
+//time info cell should keep its lock, and the capacity can only be paid as fee within the allowance
+pub fn cell_lock_and_capacity_check(index: usize, fee_allowance: u64) -> Result<(), Error> {
+    if load_cell_lock_hash(index, Source::GroupInput)? != load_cell_lock_hash(index, Source::GroupOutput)? {
+        return Err(Error::InvalidCellLock);
+    }
+    let input_capacity = load_cell_capacity(index, Source::GroupInput)?;
+    let output_capacity = load_cell_capacity(index, Source::GroupOutput)?;
+    if input_capacity.saturating_sub(output_capacity) > fee_allowance {
+        return Err(Error::InvalidCellCapacity);
+    }
+    Ok(())
+}
+
+//oracle signature is in output_type of the witness of output time info cell
+fn load_oracle_witness(index: usize) -> Result<Bytes, Error> {
+    match load_witness_args(index, Source::GroupOutput) {
+        Ok(witness_args) => match witness_args.output_type().to_opt() {
+            Some(witness) => Ok(witness.unpack()),
+            None => Err(Error::InvalidOracleWitness),
+        },
+        Err(_) => Err(Error::InvalidOracleWitness),
+    }
+}
+
+//oracle signs blake2b(index | timestamp | previous out point), so a signature cannot be replayed on other updates
+fn oracle_message(index: usize, time_index: u8, timestamp: u64) -> Result<[u8; 32], Error> {
+    let previous_out_point = load_input_out_point(index, Source::GroupInput)?;
+    let previous_out_point = match <[u8; TIME_INFO_ARGS_OUT_POINT_LEN]>::try_from(previous_out_point.as_slice()) {
+        Ok(previous_out_point) => previous_out_point,
+        Err(_) => return Err(Error::Encoding),
+    };
+    Ok(ckb_blake2b_256(&encode_oracle_message(time_index, timestamp, &previous_out_point)))
+}
+
+//secp256k1 library is loaded once for all time info cells of the script group when its code hash is set in args,
+//the dynamic loading context holds the code of library, so it should never be dropped before the script exits
+pub fn with_secp256k1_lib(
+    lib_code_hash: Option<&[u8; 32]>,
+    f: impl FnOnce(Option<&LibSecp256k1>) -> Result<(), Error>,
+) -> Result<(), Error> {
+    let lib_code_hash = match lib_code_hash {
+        Some(lib_code_hash) => lib_code_hash,
+        None => return f(None),
+    };
+    let mut context = unsafe { CKBDLContext::<[u8; 128 * 1024]>::new() };
+    let lib = LibSecp256k1::load(&mut context, lib_code_hash)?;
+    let result = f(Some(&lib));
+    core::mem::forget(context);
+    result
+}
+
+//secp256k1 signature is verified by the secp256k1 library, which should be loaded for secp256k1 oracle key,
+//and ed25519 witness carries its pubkey with signature
+pub fn oracle_signature_check(
+    index: usize,
+    time_index: u8,
+    timestamp: u64,
+    oracle_key: &OracleKey,
+    secp256k1_lib: Option<&LibSecp256k1>,
+) -> Result<(), Error> {
+    let signature = load_oracle_witness(index)?;
+    let message = oracle_message(index, time_index, timestamp)?;
+    let pubkey_hash = match (oracle_key.key_type, secp256k1_lib) {
+        (ORACLE_KEY_TYPE_SECP256K1, Some(lib)) => lib.recover_pubkey_hash(&signature, &message)?,
+        (ORACLE_KEY_TYPE_SECP256K1, None) => return Err(Error::InvalidOracleLib),
+        (ORACLE_KEY_TYPE_ED25519, _) => ed25519::recover_pubkey_hash(&signature, &message)?,
+        _ => return Err(Error::InvalidArgument),
+    };
+    if pubkey_hash != oracle_key.pubkey_hash {
+        return Err(Error::InvalidOracleSignature);
+    }
+    Ok(())
+}
+
+//at least M distinct oracles of quorum should sign their timestamps, and the timestamp of time info cell
+//should be the median of them, or every signed timestamp should be within the spread when spread is set
+pub fn oracle_quorum_check(
+    index: usize,
+    time_index: u8,
+    timestamp: u64,
+    oracle_quorum: &OracleQuorum,
+    spread: Option<u32>,
+    lib: &LibSecp256k1,
+) -> Result<Vec<OracleEntry>, Error> {
+    let witness = load_oracle_witness(index)?;
+    let attestations: Vec<_> = parse_oracle_attestations(&witness)?.collect();
+    if attestations.len() < oracle_quorum.threshold as usize {
+        return Err(Error::InvalidOracleQuorum);
+    }
+
+    let mut attested_entries = Vec::with_capacity(attestations.len());
+    for attestation in attestations {
+        let oracle_key = match oracle_quorum.keys().get(attestation.oracle_index as usize) {
+            Some(oracle_key) => oracle_key,
+            None => return Err(Error::InvalidOracleWitness),
+        };
+        let message = oracle_message(index, time_index, attestation.timestamp)?;
+        if lib.recover_pubkey_hash(&attestation.signature, &message)? != oracle_key.pubkey_hash {
+            return Err(Error::InvalidOracleSignature);
+        }
+        attested_entries.push(OracleEntry { oracle_index: attestation.oracle_index, timestamp: attestation.timestamp });
+    }
+
+    let mut timestamps: Vec<_> = attested_entries.iter().map(|entry| entry.timestamp).collect();
+    let timestamp_accepted = match spread {
+        Some(spread) => timestamps.iter().all(|attested| {
+            attested.max(&timestamp) - attested.min(&timestamp) <= spread as u64
+        }),
+        None => median_timestamp(&mut timestamps) == Some(timestamp),
+    };
+    if !timestamp_accepted {
+        return Err(Error::InvalidOracleTimestamp);
+    }
+    Ok(attested_entries)
+}
+
+//oracle entries in cell data should be exactly the attested timestamps, and timestamp should be their median
+pub fn oracle_entries_check(
+    oracle_entries: &OracleEntries,
+    timestamp: u64,
+    attested_entries: Option<&[OracleEntry]>,
+) -> Result<(), Error> {
+    if oracle_entries.median_timestamp() != Some(timestamp) {
+        return Err(Error::InvalidOracleEntries);
+    }
+    match attested_entries {
+        Some(attested_entries) if oracle_entries.entries() == attested_entries => Ok(()),
+        _ => Err(Error::InvalidOracleEntries),
+    }
+}
+
+pub fn input_cell_since_check(index: usize, timestamp: u64, tolerance: Option<u32>) -> Result<(), Error> {
+    //lock value of timestamp since is in milliseconds, timestamp should fit in it
+    let timestamp_millis = timestamp.checked_mul(1000).ok_or(Error::InvalidTimeSince)?;
+    let since = Since::new(load_input_since(index, Source::GroupInput)?);
+    let tolerance = match tolerance {
+        Some(tolerance) => tolerance,
+        None => {
+            //since should be exactly the absolute timestamp when since tolerance is not set in args
+            return match (since.flags_is_valid() && since.is_absolute(), since.extract_lock_value()) {
+                (true, Some(LockValue::Timestamp(since_millis))) if since_millis == timestamp_millis => Ok(()),
+                _ => Err(Error::InvalidTimeSince),
+            };
+        }
+    };
+
+    //since should be an absolute timestamp in [timestamp - tolerance, timestamp]
+    if !since.flags_is_valid() {
+        return Err(Error::InvalidSinceFlags);
+    }
+    if since.is_relative() {
+        return Err(Error::RelativeSince);
+    }
+    let since_millis = match since.extract_lock_value() {
+        Some(LockValue::Timestamp(since_millis)) => since_millis,
+        _ => return Err(Error::InvalidTimeSince),
+    };
+    if since_millis > timestamp_millis || timestamp_millis - since_millis > tolerance as u64 * 1000 {
         return Err(Error::InvalidTimeSince);
     }
     Ok(())
-}
\ No newline at end of file
+}
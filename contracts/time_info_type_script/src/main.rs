@@ -16,11 +16,15 @@ use ckb_std::default_alloc;
 mod entry;
 mod error;
 mod create;
+mod destroy;
+mod ed25519;
 mod helper;
+mod secp256k1;
 mod update;
 
 ckb_std::entry!(program_entry);
-default_alloc!();
+//the prefilled data of secp256k1 library takes 1M heap
+default_alloc!(4 * 1024, 2048 * 1024, 64);
 
 /// program entry
 fn program_entry() -> i8 {
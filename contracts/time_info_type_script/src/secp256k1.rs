@@ -0,0 +1,71 @@
+use alloc::{vec, vec::Vec};
+
+use ckb_std::dynamic_loading::{CKBDLContext, Symbol};
+
+use crate::error::Error;
+
+//secp256k1 library in cell deps, which exports load_prefilled_data and validate_signature,
+//see https://github.com/jjyr/ckb-dynamic-loading-secp256k1
+const CKB_SECP256K1_DATA_SIZE: usize = 1048576;
+const PUBKEY_HASH_LEN: usize = 20;
+
+type LoadPrefilledData = unsafe extern "C" fn(data: *mut u8, len: *mut u64) -> i32;
+type ValidateSignature = unsafe extern "C" fn(
+    prefilled_data: *const u8,
+    signature_buffer: *const u8,
+    signature_buffer_size: u64,
+    message_buffer: *const u8,
+    message_buffer_size: u64,
+    output: *mut u8,
+    output_len: *mut u64,
+) -> i32;
+
+pub struct LibSecp256k1 {
+    prefilled_data: Vec<u8>,
+    validate_signature: Symbol<ValidateSignature>,
+}
+
+impl LibSecp256k1 {
+    pub fn load<T>(context: &mut CKBDLContext<T>, code_hash: &[u8; 32]) -> Result<Self, Error> {
+        let lib = match context.load(code_hash) {
+            Ok(lib) => lib,
+            Err(_) => return Err(Error::InvalidOracleLib),
+        };
+        let load_prefilled_data: Option<Symbol<LoadPrefilledData>> = unsafe { lib.get(b"load_prefilled_data") };
+        let validate_signature = unsafe { lib.get(b"validate_signature") };
+        let (load_prefilled_data, validate_signature) = match (load_prefilled_data, validate_signature) {
+            (Some(load_prefilled_data), Some(validate_signature)) => (load_prefilled_data, validate_signature),
+            _ => return Err(Error::InvalidOracleLib),
+        };
+
+        //prefilled data is loaded into heap once and shared by every signature
+        let mut prefilled_data = vec![0_u8; CKB_SECP256K1_DATA_SIZE];
+        let mut len = CKB_SECP256K1_DATA_SIZE as u64;
+        if unsafe { load_prefilled_data(prefilled_data.as_mut_ptr(), &mut len as *mut u64) } != 0 {
+            return Err(Error::InvalidOracleLib);
+        }
+        Ok(LibSecp256k1 { prefilled_data, validate_signature })
+    }
+
+    //recover the blake160 pubkey hash of the signer of message
+    pub fn recover_pubkey_hash(&self, signature: &[u8], message: &[u8]) -> Result<[u8; PUBKEY_HASH_LEN], Error> {
+        let mut pubkey_hash = [0_u8; PUBKEY_HASH_LEN];
+        let mut len = PUBKEY_HASH_LEN as u64;
+        let validate_signature = &self.validate_signature;
+        let error_code = unsafe {
+            validate_signature(
+                self.prefilled_data.as_ptr(),
+                signature.as_ptr(),
+                signature.len() as u64,
+                message.as_ptr(),
+                message.len() as u64,
+                pubkey_hash.as_mut_ptr(),
+                &mut len as *mut u64,
+            )
+        };
+        if error_code != 0 || len != PUBKEY_HASH_LEN as u64 {
+            return Err(Error::InvalidOracleSignature);
+        }
+        Ok(pubkey_hash)
+    }
+}
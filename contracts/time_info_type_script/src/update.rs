@@ -1,46 +1,163 @@
+use core::convert::TryFrom;
+
 use ckb_std::{ckb_constants::Source};
+use ckb_std::high_level::load_cell_data;
+
+use time_info_types::args::TimeInfoArgs;
+use time_info_types::data::TimeInfoData;
 
 use crate::error::*;
 use crate::helper::{
     cell_args_check,
+    cell_data_version_check,
+    cell_lock_and_capacity_check,
     get_script_hash_cell_count,
-    get_timestamp_from_cell_data,
+    has_input_lock_hash,
+    header_dep_block_check,
+    header_dep_timestamp_check,
     input_cell_since_check,
-    TIME_INFO_CELL_DATA_LEN,
+    oracle_entries_check,
+    oracle_quorum_check,
+    oracle_signature_check,
+    pause_toggle_check,
+    prev_data_hash_check,
+    sequence_check,
     timestamp_check,
+    timestamp_step_check,
+    time_index_state_check,
+    update_interval_check,
+    with_secp256k1_lib,
 };
+use crate::secp256k1::LibSecp256k1;
 
 pub fn update(script_hash: [u8; 32]) -> Result<(), Error> {
-    //should only one time info cell in inout
-    if get_script_hash_cell_count(script_hash, Source::Input) != 1 {
+    //time info cells in input and output are paired by index, so their count should be equal
+    let input_count = get_script_hash_cell_count(script_hash, Source::Input);
+    let output_count = get_script_hash_cell_count(script_hash, Source::Output);
+    if input_count > output_count {
         return Err(Error::InvalidTimeInfoInput);
     }
-    //should only one time info cell in output
-    if get_script_hash_cell_count(script_hash, Source::Output) != 1 {
+    if output_count > input_count {
         return Err(Error::InvalidTImeInfoOutput);
     }
-    //check whether args of script of input not empty and equal args of output's
-    cell_args_check(script_hash)?;
+    //check whether args of script of input valid and equal args of output's
+    let time_info_args = cell_args_check(script_hash)?;
+
+    //time index state cell points to a single time index, so it can only move with one time info cell
+    if time_info_args.time_index_state_type_hash.is_some() && input_count > 1 {
+        return Err(Error::InvalidTimeIndexState);
+    }
+
+    //every pair of time info cells is validated independently
+    with_secp256k1_lib(time_info_args.secp256k1_lib_code_hash.as_ref(), |secp256k1_lib| {
+        for index in 0..input_count {
+            update_cell(index, &time_info_args, secp256k1_lib)?;
+        }
+        Ok(())
+    })
+}
+
+fn update_cell(index: usize, time_info_args: &TimeInfoArgs, secp256k1_lib: Option<&LibSecp256k1>) -> Result<(), Error> {
+    let input_cell_data = load_cell_data(index, Source::GroupInput)?;
+    let input_data = TimeInfoData::try_from(input_cell_data.as_slice())?;
+    let output_data = TimeInfoData::try_from(load_cell_data(index, Source::GroupOutput)?.as_slice())?;
+
+    //cell data version can only be changed by migration
+    cell_data_version_check(input_data.version, output_data.version)?;
+
+    //every update should be counted by sequence
+    sequence_check(input_data.sequence, output_data.sequence)?;
+
+    //output cell data should be chained to input cell data
+    prev_data_hash_check(&input_cell_data, output_data.prev_data_hash)?;
+
+    //only admin can pause or resume time info cell, which changes nothing but the paused flag
+    if input_data.paused != output_data.paused {
+        pause_toggle_check(&input_data, &output_data, time_info_args.admin_lock_hash)?;
+        if let Some(fee_allowance) = time_info_args.fee_allowance {
+            cell_lock_and_capacity_check(index, fee_allowance)?;
+        }
+        return Ok(());
+    }
 
-    let input_cell_data = crate::helper::load_cell_data(script_hash, Source::Input)?;
-    if input_cell_data.len() != TIME_INFO_CELL_DATA_LEN as usize {
-        return Err(Error::InvalidCellData);
+    //paused time info cell cannot be updated until admin resumes it
+    if output_data.paused {
+        return Err(Error::TimeInfoPaused);
     }
-    let output_cell_data = crate::helper::load_cell_data(script_hash, Source::Output)?;
-    if output_cell_data.len() != TIME_INFO_CELL_DATA_LEN as usize {
-        return Err(Error::InvalidCellData);
+
+    //only operator can update time info cell when operator lock hash is set in args
+    if let Some(operator_lock_hash) = time_info_args.operator_lock_hash {
+        if !has_input_lock_hash(operator_lock_hash) {
+            return Err(Error::InvalidOperatorAuthorization);
+        }
     }
 
-    let last_timestamp = get_timestamp_from_cell_data(&input_cell_data);
-    let current_timestamp = get_timestamp_from_cell_data(&output_cell_data);
+    let last_timestamp = input_data.timestamp;
+    let current_timestamp = output_data.timestamp;
     timestamp_check(last_timestamp, current_timestamp)?;
 
+    //time info cell should not update more frequently than min update interval
+    update_interval_check(last_timestamp, current_timestamp, time_info_args.min_update_interval())?;
+
+    //time info cell should not jump too far forward when max timestamp step is set in args
+    if let Some(max_step) = time_info_args.max_timestamp_step {
+        timestamp_step_check(last_timestamp, current_timestamp, max_step)?;
+    }
+
+    //timestamp should be close to the timestamp of header dep when header dep tolerance is set in args,
+    //and block number and epoch should be attested with timestamp by the header dep when they are in cell data
+    match (&output_data.header_dep_block, time_info_args.header_dep_tolerance) {
+        (Some(header_dep_block), tolerance) => header_dep_block_check(header_dep_block, current_timestamp, tolerance)?,
+        (None, Some(tolerance)) => header_dep_timestamp_check(current_timestamp, tolerance)?,
+        (None, None) => (),
+    }
+
+    //recorded block number cannot move backwards
+    if let (Some(last_block), Some(current_block)) = (&input_data.header_dep_block, &output_data.header_dep_block) {
+        if current_block.block_number < last_block.block_number {
+            return Err(Error::InvalidHeaderDepBlock);
+        }
+    }
+
     //check since of input cell in case time info update to early
-    input_cell_since_check(current_timestamp)?;
+    input_cell_since_check(index, current_timestamp, time_info_args.since_tolerance)?;
 
     //time index in output cell should equal time index in input cell
-    if output_cell_data[0] != input_cell_data[0] {
+    let time_index = output_data.index;
+    if time_index != input_data.index {
         return Err(Error::InvalidTimeIndex);
     }
+
+    //lock cannot be changed and capacity cannot be drained when fee allowance is set in args
+    if let Some(fee_allowance) = time_info_args.fee_allowance {
+        cell_lock_and_capacity_check(index, fee_allowance)?;
+    }
+
+    //timestamp should be signed by oracle when oracle key is set in args, and the signature is never skipped
+    //even if the secp256k1 library is not loaded
+    if let Some(oracle_key) = &time_info_args.oracle_key {
+        oracle_signature_check(index, time_index, current_timestamp, oracle_key, secp256k1_lib)?;
+    }
+
+    //timestamp should be attested by at least M oracles when oracle quorum is set in args
+    let attested_entries = match &time_info_args.oracle_quorum {
+        Some(oracle_quorum) => {
+            let lib = secp256k1_lib.ok_or(Error::InvalidOracleLib)?;
+            Some(oracle_quorum_check(
+                index, time_index, current_timestamp, oracle_quorum, time_info_args.oracle_spread, lib,
+            )?)
+        }
+        None => None,
+    };
+
+    //oracle entries can only be stored by the update attested by oracle quorum
+    if let Some(oracle_entries) = &output_data.oracle_entries {
+        oracle_entries_check(oracle_entries, current_timestamp, attested_entries.as_deref())?;
+    }
+
+    //time index state cell should move to this time index when its type hash is set in args
+    if let Some(state_type_hash) = time_info_args.time_index_state_type_hash {
+        time_index_state_check(state_type_hash, time_index, time_info_args.ring_size())?;
+    }
     Ok(())
-}
\ No newline at end of file
+}
@@ -4,30 +4,45 @@ use ckb_tool::ckb_error::assert_error_eq;
 use ckb_tool::ckb_script::ScriptError;
 use ckb_tool::ckb_types::{
     bytes::{Bytes, BytesMut},
-    core::TransactionBuilder,
+    core::{HeaderBuilder, TransactionBuilder},
     packed::*,
     prelude::*,
 };
 use ckb_tool::ckb_types::bytes::BufMut;
+use time_info_types::args::{
+    TIME_INFO_CELL_DATA_N,
+    ARGS_FIELD_MIN_UPDATE_INTERVAL,
+    ARGS_FIELD_MAX_TIMESTAMP_STEP,
+    ARGS_FIELD_HEADER_DEP_TOLERANCE,
+    ARGS_FIELD_RING_SIZE,
+    ARGS_FIELD_RING_INDEX,
+};
+use time_info_types::data::{TIME_INFO_CELL_DATA_LEN, TIME_INFO_CELL_DATA_VERSION, CELL_DATA_LEN_0};
+use time_info_types::error::{
+    ERROR_INVALID_ARGUMENT,
+    ERROR_INVALID_CELL_DATA,
+    ERROR_INVALID_TIME_INFO_OUTPUT,
+    ERROR_INVALID_TIME_INDEX,
+    ERROR_INVALID_ARGS_OUT_POINT,
+    ERROR_DUPLICATE_TIME_INDEX,
+    ERROR_INVALID_RING_GENESIS,
+    ERROR_INVALID_ORACLE_ENTRIES,
+    ERROR_INVALID_SEQUENCE,
+    ERROR_INVALID_PREV_DATA_HASH,
+    ERROR_INVALID_HEADER_DEP_BLOCK,
+    ERROR_TIME_INFO_PAUSED,
+};
 
 use super::*;
-
-const MAX_CYCLES: u64 = 10_000_000;
-const TIME_INFO_CELL_DATA_N: u8 = 12;
-const TIME_INFO_CELL_DATA_LEN: usize = 5;
-
-// error numbers
-const ERROR_TIME_INFO_ARGS: i8 = 54;
-const ERROR_TIME_INFO_CELL_DATA: i8 = 55;
-const ERROR_TIME_INFO_OUTPUT: i8 = 57;
-const ERROR_TIME_INFO_INDEX: i8 = 60;
-
-fn build_time_info_cell_data(index: u8, timestamp: u32) -> Bytes {
-    let mut time_buf = BytesMut::with_capacity(TIME_INFO_CELL_DATA_LEN);
-    time_buf.put_u8(index);
-    time_buf.put_u32(timestamp);
-    Bytes::from(time_buf.to_vec())
-}
+use crate::helper::{
+    MAX_CYCLES,
+    build_time_info_cell_data,
+    build_next_time_info_cell_data,
+    build_time_info_cell_data_with_oracle_entries,
+    build_time_info_cell_data_with_header_dep_block,
+    build_paused_time_info_cell_data,
+    build_time_info_args,
+};
 
 #[test]
 fn test_success_create() {
@@ -73,8 +88,8 @@ fn test_success_create() {
     ];
 
     let time_index = 1;
-    let timestamp = Utc::now().timestamp() as u32;
-    let outputs_data = vec![build_time_info_cell_data(time_index, timestamp)];
+    let timestamp = Utc::now().timestamp() as u64;
+    let outputs_data = vec![build_time_info_cell_data(time_index, timestamp, 0)];
 
     // build transaction
     let tx = TransactionBuilder::default()
@@ -140,10 +155,7 @@ fn test_error_with_invalid_output() {
             .build(),
     ];
 
-    let mut buf = BytesMut::with_capacity(5);
-    buf.put_u8(0);
-    buf.put_u32(Utc::now().timestamp() as u32);
-    let outputs_data = vec![Bytes::from(buf.to_vec()), Bytes::new()];
+    let outputs_data = vec![build_time_info_cell_data(0, Utc::now().timestamp() as u64, 0), Bytes::new()];
 
     // build transaction
     let tx = TransactionBuilder::default()
@@ -159,7 +171,7 @@ fn test_error_with_invalid_output() {
     let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
     assert_error_eq!(
         err,
-        ScriptError::ValidationFailure(ERROR_TIME_INFO_OUTPUT).output_type_script(0)
+        ScriptError::ValidationFailure(ERROR_INVALID_TIME_INFO_OUTPUT).output_type_script(0)
     );
 }
 
@@ -205,8 +217,8 @@ fn test_error_with_invalid_cell_data() {
             .build(),
     ];
 
-    let mut buf = BytesMut::with_capacity(5);
-    buf.put_u8(0);
+    let mut buf = BytesMut::with_capacity(TIME_INFO_CELL_DATA_LEN);
+    buf.put_u8(TIME_INFO_CELL_DATA_VERSION);
     let outputs_data = vec![Bytes::from(buf.to_vec())];
 
     // build transaction
@@ -222,7 +234,7 @@ fn test_error_with_invalid_cell_data() {
     let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
     assert_error_eq!(
         err,
-        ScriptError::ValidationFailure(ERROR_TIME_INFO_CELL_DATA).output_type_script(0)
+        ScriptError::ValidationFailure(ERROR_INVALID_CELL_DATA).output_type_script(0)
     );
 }
 
@@ -269,10 +281,7 @@ fn test_error_with_invalid_time_index() {
             .build(),
     ];
 
-    let mut buf = BytesMut::with_capacity(5);
-    buf.put_u8(TIME_INFO_CELL_DATA_N * 2);
-    buf.put_u32(Utc::now().timestamp() as u32);
-    let outputs_data = vec![Bytes::from(buf.to_vec())];
+    let outputs_data = vec![build_time_info_cell_data(TIME_INFO_CELL_DATA_N * 2, Utc::now().timestamp() as u64, 0)];
 
     // build transaction
     let tx = TransactionBuilder::default()
@@ -288,7 +297,7 @@ fn test_error_with_invalid_time_index() {
     let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
     assert_error_eq!(
         err,
-        ScriptError::ValidationFailure(ERROR_TIME_INFO_INDEX).output_type_script(0)
+        ScriptError::ValidationFailure(ERROR_INVALID_TIME_INDEX).output_type_script(0)
     );
 }
 
@@ -335,7 +344,134 @@ fn test_error_with_invalid_args() {
             .build(),
     ];
 
-    let mut buf = BytesMut::with_capacity(5);
+    let outputs_data = vec![build_time_info_cell_data(0, Utc::now().timestamp() as u64, 0)];
+
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(ERROR_INVALID_ARGUMENT).output_type_script(0)
+    );
+}
+
+#[test]
+fn test_error_with_unconsumed_args_out_point() {
+    let mut context = Context::default();
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    // prepare cells
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+
+    // deploy contract, the out point in args is not consumed by the transaction
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    let type_script = context.
+        build_script(&out_point, out_point.as_bytes()).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .build();
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(500u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script).pack())
+            .build(),
+    ];
+
+    let outputs_data = vec![build_time_info_cell_data(0, Utc::now().timestamp() as u64, 0)];
+
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(ERROR_INVALID_ARGS_OUT_POINT).output_type_script(0)
+    );
+}
+
+#[test]
+fn test_error_with_legacy_cell_data() {
+    let mut context = Context::default();
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    // prepare cells
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+
+    // deploy contract
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    let type_script = context.
+        build_script(&out_point, input_out_point.as_bytes()).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .build();
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(500u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script).pack())
+            .build(),
+    ];
+
+    // new time info cell cannot use legacy cell data with u32 timestamp
+    let mut buf = BytesMut::with_capacity(CELL_DATA_LEN_0);
     buf.put_u8(0);
     buf.put_u32(Utc::now().timestamp() as u32);
     let outputs_data = vec![Bytes::from(buf.to_vec())];
@@ -354,6 +490,1025 @@ fn test_error_with_invalid_args() {
     let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
     assert_error_eq!(
         err,
-        ScriptError::ValidationFailure(ERROR_TIME_INFO_ARGS).output_type_script(0)
+        ScriptError::ValidationFailure(ERROR_INVALID_CELL_DATA).output_type_script(0)
+    );
+}
+
+#[test]
+fn test_success_create_with_ring_size() {
+    let mut context = Context::default();
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    // prepare cells
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+
+    // deploy contract with a ring of 6 time info cells
+    let ring_size: u8 = 6;
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    let type_script = context.
+        build_script(
+            &out_point,
+            build_time_info_args(&input_out_point, vec![(ARGS_FIELD_RING_SIZE, Bytes::from(vec![ring_size]))]),
+        ).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .build();
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(500u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script).pack())
+            .build(),
+    ];
+
+    let outputs_data = vec![build_time_info_cell_data(ring_size - 1, Utc::now().timestamp() as u64, 0)];
+
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_error_with_time_index_out_of_ring_size() {
+    let mut context = Context::default();
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    // prepare cells
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+
+    // deploy contract with a ring of 6 time info cells
+    let ring_size: u8 = 6;
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    let type_script = context.
+        build_script(
+            &out_point,
+            build_time_info_args(&input_out_point, vec![(ARGS_FIELD_RING_SIZE, Bytes::from(vec![ring_size]))]),
+        ).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .build();
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(500u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script).pack())
+            .build(),
+    ];
+
+    let outputs_data = vec![build_time_info_cell_data(ring_size, Utc::now().timestamp() as u64, 0)];
+
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(ERROR_INVALID_TIME_INDEX).output_type_script(0)
+    );
+}
+
+#[test]
+fn test_success_create_ring_family() {
+    let mut context = Context::default();
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    // prepare cells
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+
+    // deploy contract
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point.clone()).
+        build();
+
+    // a family of 2 time info cells, the ring index is bound into args
+    let ring_size: u8 = 2;
+    let now = Utc::now().timestamp() as u64;
+    let mut type_scripts = vec![];
+    let mut outputs_data = vec![];
+    for time_index in 0..ring_size {
+        let args = build_time_info_args(&input_out_point, vec![
+            (ARGS_FIELD_RING_SIZE, Bytes::from(vec![ring_size])),
+            (ARGS_FIELD_RING_INDEX, Bytes::from(vec![time_index])),
+        ]);
+        type_scripts.push(context.build_script(&out_point, args).expect("script"));
+        outputs_data.push(build_time_info_cell_data(time_index, now, 0));
+    }
+
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .build();
+    let outputs: Vec<CellOutput> = type_scripts.iter().map(|type_script| {
+        CellOutput::new_builder()
+            .capacity(500u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build()
+    }).collect();
+
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_error_with_unbound_ring_index() {
+    let mut context = Context::default();
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    // prepare cells
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+
+    // deploy contract
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point.clone()).
+        build();
+
+    // the index in cell data is different from the ring index in args
+    let args = build_time_info_args(&input_out_point, vec![(ARGS_FIELD_RING_INDEX, Bytes::from(vec![1]))]);
+    let type_scripts = vec![context.build_script(&out_point, args).expect("script")];
+    let outputs_data = vec![build_time_info_cell_data(0, Utc::now().timestamp() as u64, 0)];
+
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .build();
+    let outputs: Vec<CellOutput> = type_scripts.iter().map(|type_script| {
+        CellOutput::new_builder()
+            .capacity(500u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build()
+    }).collect();
+
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(ERROR_INVALID_TIME_INDEX).output_type_script(0)
+    );
+}
+
+#[test]
+fn test_error_with_duplicate_time_index_in_family() {
+    let mut context = Context::default();
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    // prepare cells
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+
+    // deploy contract
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point.clone()).
+        build();
+
+    // two time info cells of the same family use the same time index
+    let now = Utc::now().timestamp() as u64;
+    let type_scripts = vec![
+        context.
+            build_script(&out_point, build_time_info_args(&input_out_point, vec![(ARGS_FIELD_RING_INDEX, Bytes::from(vec![0]))])).
+            expect("script"),
+        context.
+            build_script(&out_point, input_out_point.as_bytes()).
+            expect("script"),
+    ];
+    let outputs_data = vec![
+        build_time_info_cell_data(0, now, 0),
+        build_time_info_cell_data(0, now, 0),
+    ];
+
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .build();
+    let outputs: Vec<CellOutput> = type_scripts.iter().map(|type_script| {
+        CellOutput::new_builder()
+            .capacity(500u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build()
+    }).collect();
+
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(ERROR_DUPLICATE_TIME_INDEX).output_type_script(0)
+    );
+}
+
+#[test]
+fn test_success_create_genesis_ring() {
+    let mut context = Context::default();
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    // prepare cells
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+
+    // deploy contract
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point.clone()).
+        build();
+    // genesis of a complete ring with the default ring size 12
+    let now = Utc::now().timestamp() as u64;
+    let mut type_scripts = vec![];
+    let mut outputs_data = vec![];
+    for time_index in 0..12u8 {
+        let args = build_time_info_args(&input_out_point, vec![(ARGS_FIELD_RING_INDEX, Bytes::from(vec![time_index]))]);
+        type_scripts.push(context.build_script(&out_point, args).expect("script"));
+        outputs_data.push(build_time_info_cell_data(time_index, now, 0));
+    }
+
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .build();
+    let outputs: Vec<CellOutput> = type_scripts.iter().map(|type_script| {
+        CellOutput::new_builder()
+            .capacity(500u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build()
+    }).collect();
+
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_error_with_partial_genesis_ring() {
+    let mut context = Context::default();
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    // prepare cells
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+
+    // deploy contract
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point.clone()).
+        build();
+
+    // the ring size is 3, but only time index 0 and 1 are created
+    let ring_size: u8 = 3;
+    let now = Utc::now().timestamp() as u64;
+    let mut type_scripts = vec![];
+    let mut outputs_data = vec![];
+    for time_index in 0..ring_size - 1 {
+        let args = build_time_info_args(&input_out_point, vec![
+            (ARGS_FIELD_RING_SIZE, Bytes::from(vec![ring_size])),
+            (ARGS_FIELD_RING_INDEX, Bytes::from(vec![time_index])),
+        ]);
+        type_scripts.push(context.build_script(&out_point, args).expect("script"));
+        outputs_data.push(build_time_info_cell_data(time_index, now, 0));
+    }
+
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .build();
+    let outputs: Vec<CellOutput> = type_scripts.iter().map(|type_script| {
+        CellOutput::new_builder()
+            .capacity(500u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build()
+    }).collect();
+
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(ERROR_INVALID_RING_GENESIS).output_type_script(0)
+    );
+}
+
+#[test]
+fn test_error_with_inconsistent_genesis_ring_args() {
+    let mut context = Context::default();
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    // prepare cells
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+
+    // deploy contract
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point.clone()).
+        build();
+
+    // the time info cell of index 1 has a different min update interval
+    let ring_size: u8 = 2;
+    let now = Utc::now().timestamp() as u64;
+    let mut type_scripts = vec![];
+    let mut outputs_data = vec![];
+    for time_index in 0..ring_size {
+        let mut fields = vec![
+            (ARGS_FIELD_RING_SIZE, Bytes::from(vec![ring_size])),
+            (ARGS_FIELD_RING_INDEX, Bytes::from(vec![time_index])),
+        ];
+        if time_index == 1 {
+            fields.push((ARGS_FIELD_MIN_UPDATE_INTERVAL, Bytes::from(120u32.to_be_bytes().to_vec())));
+        }
+        let args = build_time_info_args(&input_out_point, fields);
+        type_scripts.push(context.build_script(&out_point, args).expect("script"));
+        outputs_data.push(build_time_info_cell_data(time_index, now, 0));
+    }
+
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .build();
+    let outputs: Vec<CellOutput> = type_scripts.iter().map(|type_script| {
+        CellOutput::new_builder()
+            .capacity(500u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build()
+    }).collect();
+
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(ERROR_INVALID_RING_GENESIS).output_type_script(0)
+    );
+}
+
+#[test]
+fn test_error_with_oracle_entries() {
+// deploy contract
+    let mut context = Context::default();
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+    // prepare cells
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    let type_script = context.
+        build_script(&out_point, input_out_point.as_bytes()).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .build();
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(500u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script).pack())
+            .build(),
+    ];
+
+    // oracle entries cannot be attested on create
+    let now = Utc::now().timestamp() as u64;
+    let outputs_data = vec![
+        build_time_info_cell_data_with_oracle_entries(build_time_info_cell_data(0, now, 0), vec![(0, now)]),
+    ];
+
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(ERROR_INVALID_ORACLE_ENTRIES).output_type_script(0)
+    );
+}
+
+#[test]
+fn test_error_with_nonzero_sequence() {
+// deploy contract
+    let mut context = Context::default();
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+    // prepare cells
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    let type_script = context.
+        build_script(&out_point, input_out_point.as_bytes()).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .build();
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(500u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script).pack())
+            .build(),
+    ];
+
+    // sequence of new time info cell should start at zero
+    let outputs_data = vec![build_time_info_cell_data(0, Utc::now().timestamp() as u64, 1)];
+
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(ERROR_INVALID_SEQUENCE).output_type_script(0)
+    );
+}
+
+#[test]
+fn test_error_with_chained_cell_data() {
+// deploy contract
+    let mut context = Context::default();
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+    // prepare cells
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    let type_script = context.
+        build_script(&out_point, input_out_point.as_bytes()).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .build();
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(500u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script).pack())
+            .build(),
+    ];
+
+    // hash chain should start from new time info cell
+    let now = Utc::now().timestamp() as u64;
+    let outputs_data = vec![build_next_time_info_cell_data(&build_time_info_cell_data(0, now, 0), 0, now)];
+
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(ERROR_INVALID_PREV_DATA_HASH).output_type_script(0)
+    );
+}
+
+#[test]
+fn test_error_with_max_timestamp_step_below_min_update_interval() {
+// deploy contract
+    let mut context = Context::default();
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+    // prepare cells
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    let type_script = context.
+        build_script(&out_point, build_time_info_args(
+            &input_out_point,
+            vec![(ARGS_FIELD_MAX_TIMESTAMP_STEP, Bytes::from(30u32.to_be_bytes().to_vec()))],
+        )).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .build();
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(500u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script).pack())
+            .build(),
+    ];
+
+    // max timestamp step below the default min update interval makes time info cell never updatable
+    let now = Utc::now().timestamp() as u64;
+    let outputs_data = vec![build_time_info_cell_data(0, now, 0)];
+
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(ERROR_INVALID_ARGUMENT).output_type_script(0)
+    );
+}
+
+#[test]
+fn test_success_create_with_header_dep_block() {
+    // deploy contract
+    let mut context = Context::default();
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+    // prepare cells
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    let type_script = context.
+        build_script(&out_point, build_time_info_args(
+            &input_out_point,
+            vec![(ARGS_FIELD_HEADER_DEP_TOLERANCE, Bytes::from(60u32.to_be_bytes().to_vec()))],
+        )).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .build();
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(500u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script).pack())
+            .build(),
+    ];
+
+    // block number and epoch of the first header dep are attested with timestamp
+    let now = Utc::now().timestamp() as u64;
+    let header = HeaderBuilder::default()
+        .timestamp((now * 1000).pack())
+        .number(1000u64.pack())
+        .epoch(42u64.pack())
+        .build();
+    context.insert_header(header.clone());
+    let outputs_data = vec![build_time_info_cell_data_with_header_dep_block(build_time_info_cell_data(0, now, 0), 1000, 42)];
+
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .header_dep(header.hash())
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_error_create_with_mismatched_header_dep_block() {
+    // deploy contract
+    let mut context = Context::default();
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+    // prepare cells
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    let type_script = context.
+        build_script(&out_point, build_time_info_args(
+            &input_out_point,
+            vec![(ARGS_FIELD_HEADER_DEP_TOLERANCE, Bytes::from(60u32.to_be_bytes().to_vec()))],
+        )).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .build();
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(500u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script).pack())
+            .build(),
+    ];
+
+    // block number in cell data is not the one of the first header dep
+    let now = Utc::now().timestamp() as u64;
+    let header = HeaderBuilder::default()
+        .timestamp((now * 1000).pack())
+        .number(1000u64.pack())
+        .epoch(42u64.pack())
+        .build();
+    context.insert_header(header.clone());
+    let outputs_data = vec![build_time_info_cell_data_with_header_dep_block(build_time_info_cell_data(0, now, 0), 999, 42)];
+
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .header_dep(header.hash())
+        .build();
+    let tx = context.complete_tx(tx);
+
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(ERROR_INVALID_HEADER_DEP_BLOCK).output_type_script(0)
+    );
+}
+
+#[test]
+fn test_error_create_paused_time_info_cell() {
+    // deploy contract
+    let mut context = Context::default();
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+    // prepare cells
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    let type_script = context.
+        build_script(&out_point, build_time_info_args(&input_out_point, vec![])).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .build();
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(500u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script).pack())
+            .build(),
+    ];
+
+    // new time info cell cannot be paused
+    let now = Utc::now().timestamp() as u64;
+    let outputs_data = vec![build_paused_time_info_cell_data(build_time_info_cell_data(0, now, 0))];
+
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(ERROR_TIME_INFO_PAUSED).output_type_script(0)
     );
 }
@@ -0,0 +1,229 @@
+use chrono::*;
+use ckb_testtool::{builtin::ALWAYS_SUCCESS, context::Context};
+use ckb_tool::ckb_error::assert_error_eq;
+use ckb_tool::ckb_script::ScriptError;
+use ckb_tool::ckb_types::{
+    bytes::{Bytes, BytesMut},
+    core::TransactionBuilder,
+    packed::*,
+    prelude::*,
+};
+use ckb_tool::ckb_types::bytes::BufMut;
+use time_info_types::args::ARGS_FIELD_ADMIN_LOCK_HASH;
+use time_info_types::error::{ERROR_DESTROY_NOT_ALLOWED, ERROR_INVALID_ADMIN_AUTHORIZATION};
+
+use super::*;
+use crate::helper::{MAX_CYCLES, build_time_info_cell_data};
+
+fn build_admin_time_info_args(out_point: &OutPoint, admin_lock_hash: Option<Byte32>) -> Bytes {
+    let mut args_buf = BytesMut::from(out_point.as_slice());
+    if let Some(lock_hash) = admin_lock_hash {
+        args_buf.put_u8(ARGS_FIELD_ADMIN_LOCK_HASH);
+        args_buf.put_u8(32);
+        args_buf.put_slice(lock_hash.as_slice());
+    }
+    Bytes::from(args_buf.to_vec())
+}
+
+#[test]
+fn test_success_destroy() {
+    let mut context = Context::default();
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let admin_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![1]))
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    // deploy contract
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    let type_script = context.
+        build_script(&out_point, build_admin_time_info_args(&out_point, Some(admin_lock_script.calc_script_hash()))).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    // prepare cells
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        build_time_info_cell_data(0, Utc::now().timestamp() as u64, 0),
+    );
+    let admin_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(admin_lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+
+    let inputs = vec![
+        CellInput::new_builder()
+            .previous_output(input_out_point)
+            .build(),
+        CellInput::new_builder()
+            .previous_output(admin_out_point)
+            .build(),
+    ];
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(2000u64.pack())
+            .lock(lock_script.clone())
+            .build(),
+    ];
+
+    let outputs_data = vec![Bytes::new()];
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .inputs(inputs)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_error_destroy_without_admin_args() {
+    let mut context = Context::default();
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    // deploy contract
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    let type_script = context.
+        build_script(&out_point, build_admin_time_info_args(&out_point, None)).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    // prepare cells
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        build_time_info_cell_data(0, Utc::now().timestamp() as u64, 0),
+    );
+
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .build();
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .build(),
+    ];
+
+    let outputs_data = vec![Bytes::new()];
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(ERROR_DESTROY_NOT_ALLOWED).input_type_script(0)
+    );
+}
+
+#[test]
+fn test_error_destroy_without_admin_input() {
+    let mut context = Context::default();
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let admin_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![1]))
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    // deploy contract
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    let type_script = context.
+        build_script(&out_point, build_admin_time_info_args(&out_point, Some(admin_lock_script.calc_script_hash()))).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    // prepare cells
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        build_time_info_cell_data(0, Utc::now().timestamp() as u64, 0),
+    );
+
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .build();
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .build(),
+    ];
+
+    let outputs_data = vec![Bytes::new()];
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(ERROR_INVALID_ADMIN_AUTHORIZATION).input_type_script(0)
+    );
+}
@@ -0,0 +1,79 @@
+use std::convert::TryFrom;
+use std::fs;
+
+use ckb_tool::ckb_types::{
+    bytes::{Bytes, BytesMut},
+    packed::*,
+    prelude::*,
+};
+use ckb_tool::ckb_types::bytes::BufMut;
+use time_info_types::chain::{ckb_blake2b_256, GENESIS_PREV_DATA_HASH};
+use time_info_types::data::{
+    encode_time_info_cell_data,
+    TimeInfoData,
+    CELL_DATA_FLAG_HEADER_DEP_BLOCK,
+    CELL_DATA_FLAG_ORACLE_ENTRIES,
+    CELL_DATA_FLAG_PAUSED,
+};
+use time_info_types::state::encode_time_index_state_cell_data;
+
+pub const MAX_CYCLES: u64 = 10_000_000;
+
+//shared library of https://github.com/jjyr/ckb-dynamic-loading-secp256k1 which exports load_prefilled_data and
+//validate_signature, it is not built by capsule, so CI builds it at a pinned commit and copies it to deps/
+const SECP256K1_LIB_PATH: &str = "../deps/secp256k1_lib.so";
+pub const TIME_INFO_UPDATE_INTERVAL: u64 = time_info_types::args::TIME_INFO_UPDATE_INTERVAL as u64;
+
+pub fn load_secp256k1_lib() -> Bytes {
+    fs::read(SECP256K1_LIB_PATH).expect("secp256k1 library").into()
+}
+
+pub fn build_time_info_cell_data(index: u8, timestamp: u64, sequence: u64) -> Bytes {
+    Bytes::from(encode_time_info_cell_data(index, timestamp, sequence, &GENESIS_PREV_DATA_HASH).to_vec())
+}
+
+//build cell data of the update which is chained to the last cell data
+pub fn build_next_time_info_cell_data(last_cell_data: &Bytes, index: u8, timestamp: u64) -> Bytes {
+    let last_sequence = TimeInfoData::try_from(last_cell_data.as_ref()).ok().and_then(|data| data.sequence);
+    let sequence = last_sequence.map_or(1, |sequence| sequence + 1);
+    Bytes::from(encode_time_info_cell_data(index, timestamp, sequence, &ckb_blake2b_256(last_cell_data)).to_vec())
+}
+
+pub fn build_time_info_cell_data_with_oracle_entries(cell_data: Bytes, entries: Vec<(u8, u64)>) -> Bytes {
+    let mut time_buf = BytesMut::from(cell_data.as_ref());
+    time_buf[1] |= CELL_DATA_FLAG_ORACLE_ENTRIES;
+    time_buf.put_u8(entries.len() as u8);
+    for (oracle_index, oracle_timestamp) in entries {
+        time_buf.put_u8(oracle_index);
+        time_buf.put_u64(oracle_timestamp);
+    }
+    Bytes::from(time_buf.to_vec())
+}
+
+pub fn build_time_info_cell_data_with_header_dep_block(cell_data: Bytes, block_number: u64, epoch: u64) -> Bytes {
+    let mut time_buf = BytesMut::from(cell_data.as_ref());
+    time_buf[1] |= CELL_DATA_FLAG_HEADER_DEP_BLOCK;
+    time_buf.put_u64(block_number);
+    time_buf.put_u64(epoch);
+    Bytes::from(time_buf.to_vec())
+}
+
+pub fn build_paused_time_info_cell_data(cell_data: Bytes) -> Bytes {
+    let mut time_buf = BytesMut::from(cell_data.as_ref());
+    time_buf[1] |= CELL_DATA_FLAG_PAUSED;
+    Bytes::from(time_buf.to_vec())
+}
+
+pub fn build_time_index_state_cell_data(index: u8, ring_size: u8) -> Bytes {
+    Bytes::from(encode_time_index_state_cell_data(index, ring_size).to_vec())
+}
+
+pub fn build_time_info_args(out_point: &OutPoint, fields: Vec<(u8, Bytes)>) -> Bytes {
+    let mut args_buf = BytesMut::from(out_point.as_slice());
+    for (field_id, value) in fields {
+        args_buf.put_u8(field_id);
+        args_buf.put_u8(value.len() as u8);
+        args_buf.put_slice(&value);
+    }
+    Bytes::from(args_buf.to_vec())
+}
@@ -5,9 +5,18 @@ use std::str::FromStr;
 
 use ckb_tool::ckb_types::bytes::Bytes;
 
+#[cfg(test)]
+mod helper;
 #[cfg(test)]
 mod create_tests;
+#[cfg(test)]
 mod update_tests;
+#[cfg(test)]
+mod destroy_tests;
+#[cfg(test)]
+mod time_index_state_tests;
+#[cfg(test)]
+mod types_tests;
 
 const TEST_ENV_VAR: &str = "CAPSULE_TEST_ENV";
 
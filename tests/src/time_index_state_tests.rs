@@ -0,0 +1,1033 @@
+use chrono::*;
+use ckb_testtool::{builtin::ALWAYS_SUCCESS, context::Context};
+use ckb_tool::ckb_error::assert_error_eq;
+use ckb_tool::ckb_script::ScriptError;
+use ckb_tool::ckb_types::{
+    bytes::Bytes,
+    core::TransactionBuilder,
+    packed::*,
+    prelude::*,
+};
+use time_info_types::args::{
+    TIME_INFO_CELL_DATA_N,
+    TIME_INFO_ARGS_OUT_POINT_LEN,
+    ARGS_FIELD_ADMIN_LOCK_HASH,
+    ARGS_FIELD_OPERATOR_LOCK_HASH,
+    ARGS_FIELD_TIME_INDEX_STATE_TYPE_HASH,
+};
+use time_info_types::state::ARGS_FIELD_TIME_INFO_FAMILY;
+use time_info_types::error::{
+    ERROR_INVALID_TIME_INDEX_STATE,
+    STATE_ERROR_INVALID_TIME_INDEX,
+    STATE_ERROR_INVALID_RING_SIZE,
+    STATE_ERROR_INVALID_TIME_INFO_UPDATE,
+    STATE_ERROR_INVALID_ARGS_OUT_POINT,
+    STATE_ERROR_DESTROY_NOT_ALLOWED,
+    STATE_ERROR_INVALID_ADMIN_AUTHORIZATION,
+    STATE_ERROR_INVALID_OPERATOR_AUTHORIZATION,
+};
+
+use super::*;
+use crate::helper::{
+    MAX_CYCLES,
+    TIME_INFO_UPDATE_INTERVAL,
+    build_time_info_cell_data,
+    build_next_time_info_cell_data,
+    build_time_index_state_cell_data,
+    build_time_info_args,
+};
+
+//time info family = code hash | hash type | out point in args of time info type script deployed at out point
+fn build_time_info_family(context: &mut Context, time_info_out_point: &OutPoint) -> Bytes {
+    let time_info_type_script = context.
+        build_script(time_info_out_point, time_info_out_point.as_bytes()).
+        expect("script");
+    let mut family = time_info_type_script.code_hash().as_slice().to_vec();
+    family.extend_from_slice(time_info_type_script.hash_type().as_slice());
+    family.extend_from_slice(&time_info_out_point.as_slice()[..TIME_INFO_ARGS_OUT_POINT_LEN]);
+    Bytes::from(family)
+}
+
+#[test]
+fn test_success_create_time_index_state() {
+    let mut context = Context::default();
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    // prepare cells
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+
+    // deploy contract
+    let contract_bin: Bytes = Loader::default().load_binary("time_index_state_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    let type_script = context.
+        build_script(&out_point, input_out_point.as_bytes()).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .build();
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(500u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+    ];
+
+    let outputs_data = vec![build_time_index_state_cell_data(0, TIME_INFO_CELL_DATA_N)];
+
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_error_create_with_time_index_out_of_ring() {
+    let mut context = Context::default();
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    // prepare cells
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+
+    // deploy contract
+    let contract_bin: Bytes = Loader::default().load_binary("time_index_state_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    let type_script = context.
+        build_script(&out_point, input_out_point.as_bytes()).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .build();
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(500u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+    ];
+
+    // time index should be less than N
+    let outputs_data = vec![build_time_index_state_cell_data(TIME_INFO_CELL_DATA_N, TIME_INFO_CELL_DATA_N)];
+
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(STATE_ERROR_INVALID_TIME_INDEX).output_type_script(0)
+    );
+}
+
+#[test]
+fn test_success_update_time_index_state() {
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("time_index_state_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    let type_script = context.
+        build_script(&out_point, out_point.as_bytes()).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    // time index moves from the last time info cell back to the first one
+    let input_data = build_time_index_state_cell_data(TIME_INFO_CELL_DATA_N - 1, TIME_INFO_CELL_DATA_N);
+    let outputs_data = vec![build_time_index_state_cell_data(0, TIME_INFO_CELL_DATA_N)];
+
+    // prepare cells
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        input_data,
+    );
+
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .build();
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+    ];
+
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_error_update_skip_time_index() {
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("time_index_state_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    let type_script = context.
+        build_script(&out_point, out_point.as_bytes()).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    // time index should move to the next one, instead of skipping index 1
+    let input_data = build_time_index_state_cell_data(0, TIME_INFO_CELL_DATA_N);
+    let outputs_data = vec![build_time_index_state_cell_data(2, TIME_INFO_CELL_DATA_N)];
+
+    // prepare cells
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        input_data,
+    );
+
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .build();
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+    ];
+
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(STATE_ERROR_INVALID_TIME_INDEX).input_type_script(0)
+    );
+}
+
+#[test]
+fn test_error_update_change_ring_size() {
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("time_index_state_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    let type_script = context.
+        build_script(&out_point, out_point.as_bytes()).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    // N cannot be changed on update
+    let input_data = build_time_index_state_cell_data(0, TIME_INFO_CELL_DATA_N);
+    let outputs_data = vec![build_time_index_state_cell_data(1, TIME_INFO_CELL_DATA_N + 1)];
+
+    // prepare cells
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        input_data,
+    );
+
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .build();
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+    ];
+
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(STATE_ERROR_INVALID_RING_SIZE).input_type_script(0)
+    );
+}
+
+#[test]
+fn test_success_update_with_time_info_cell() {
+    let mut context = Context::default();
+    // deploy time info type script and time index state type script
+    let time_info_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let time_info_out_point = context.deploy_cell(time_info_bin);
+    let time_info_script_dep = CellDep::new_builder().
+        out_point(time_info_out_point.clone()).
+        build();
+    let state_bin: Bytes = Loader::default().load_binary("time_index_state_type_script");
+    let state_out_point = context.deploy_cell(state_bin);
+    let state_script_dep = CellDep::new_builder().
+        out_point(state_out_point.clone()).
+        build();
+
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    // time info cell is bound to time index state cell by the type hash in args
+    let state_type_script = context.
+        build_script(&state_out_point, state_out_point.as_bytes()).
+        expect("script");
+    let time_info_type_script = context.
+        build_script(&time_info_out_point, build_time_info_args(&time_info_out_point, vec![
+            (ARGS_FIELD_TIME_INDEX_STATE_TYPE_HASH, state_type_script.calc_script_hash().as_bytes()),
+        ])).
+        expect("script");
+
+    // time index state moves to 0, and time info cell of index 0 is updated
+    let time_index = 0;
+    let last_state_index = TIME_INFO_CELL_DATA_N - 1;
+
+    let now = Utc::now().timestamp() as u64;
+
+    // prepare cells
+    let input_data = build_time_info_cell_data(time_index, now - TIME_INFO_UPDATE_INTERVAL, 0);
+    let time_info_input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(time_info_type_script.clone()).pack())
+            .build(),
+        input_data.clone(),
+    );
+    let state_input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(state_type_script.clone()).pack())
+            .build(),
+        build_time_index_state_cell_data(last_state_index, TIME_INFO_CELL_DATA_N),
+    );
+
+    let since: u64 = 1 << 62;
+    let inputs = vec![
+        CellInput::new_builder()
+            .previous_output(time_info_input_out_point)
+            .since((since + now).pack())
+            .build(),
+        CellInput::new_builder()
+            .previous_output(state_input_out_point)
+            .build(),
+    ];
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(time_info_type_script.clone()).pack())
+            .build(),
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(state_type_script.clone()).pack())
+            .build(),
+    ];
+    let outputs_data = vec![
+        build_next_time_info_cell_data(&input_data, time_index, now),
+        build_time_index_state_cell_data((last_state_index + 1) % TIME_INFO_CELL_DATA_N, TIME_INFO_CELL_DATA_N),
+    ];
+
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .inputs(inputs)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(time_info_script_dep)
+        .cell_dep(state_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_error_update_with_stale_time_info_cell() {
+    let mut context = Context::default();
+    // deploy time info type script and time index state type script
+    let time_info_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let time_info_out_point = context.deploy_cell(time_info_bin);
+    let time_info_script_dep = CellDep::new_builder().
+        out_point(time_info_out_point.clone()).
+        build();
+    let state_bin: Bytes = Loader::default().load_binary("time_index_state_type_script");
+    let state_out_point = context.deploy_cell(state_bin);
+    let state_script_dep = CellDep::new_builder().
+        out_point(state_out_point.clone()).
+        build();
+
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    // time info cell is bound to time index state cell by the type hash in args
+    let state_type_script = context.
+        build_script(&state_out_point, state_out_point.as_bytes()).
+        expect("script");
+    let time_info_type_script = context.
+        build_script(&time_info_out_point, build_time_info_args(&time_info_out_point, vec![
+            (ARGS_FIELD_TIME_INDEX_STATE_TYPE_HASH, state_type_script.calc_script_hash().as_bytes()),
+        ])).
+        expect("script");
+
+    // time index state moves to 0, but time info cell of index 1 is updated
+    let time_index = 1;
+    let last_state_index = TIME_INFO_CELL_DATA_N - 1;
+
+    let now = Utc::now().timestamp() as u64;
+
+    // prepare cells
+    let input_data = build_time_info_cell_data(time_index, now - TIME_INFO_UPDATE_INTERVAL, 0);
+    let time_info_input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(time_info_type_script.clone()).pack())
+            .build(),
+        input_data.clone(),
+    );
+    let state_input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(state_type_script.clone()).pack())
+            .build(),
+        build_time_index_state_cell_data(last_state_index, TIME_INFO_CELL_DATA_N),
+    );
+
+    let since: u64 = 1 << 62;
+    let inputs = vec![
+        CellInput::new_builder()
+            .previous_output(time_info_input_out_point)
+            .since((since + now).pack())
+            .build(),
+        CellInput::new_builder()
+            .previous_output(state_input_out_point)
+            .build(),
+    ];
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(time_info_type_script.clone()).pack())
+            .build(),
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(state_type_script.clone()).pack())
+            .build(),
+    ];
+    let outputs_data = vec![
+        build_next_time_info_cell_data(&input_data, time_index, now),
+        build_time_index_state_cell_data((last_state_index + 1) % TIME_INFO_CELL_DATA_N, TIME_INFO_CELL_DATA_N),
+    ];
+
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .inputs(inputs)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(time_info_script_dep)
+        .cell_dep(state_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(ERROR_INVALID_TIME_INDEX_STATE).input_type_script(0)
+    );
+}
+
+#[test]
+fn test_success_update_with_time_info_family() {
+    let mut context = Context::default();
+    // deploy time info type script and time index state type script
+    let time_info_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let time_info_out_point = context.deploy_cell(time_info_bin);
+    let time_info_script_dep = CellDep::new_builder().
+        out_point(time_info_out_point.clone()).
+        build();
+    let state_bin: Bytes = Loader::default().load_binary("time_index_state_type_script");
+    let state_out_point = context.deploy_cell(state_bin);
+    let state_script_dep = CellDep::new_builder().
+        out_point(state_out_point.clone()).
+        build();
+
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    // time index state cell is bound to the family of time info cell, and time info cell is bound to
+    // time index state cell by the type hash in args
+    let time_info_family = build_time_info_family(&mut context, &time_info_out_point);
+    let state_type_script = context.
+        build_script(&state_out_point, build_time_info_args(&state_out_point, vec![
+            (ARGS_FIELD_TIME_INFO_FAMILY, time_info_family),
+        ])).
+        expect("script");
+    let time_info_type_script = context.
+        build_script(&time_info_out_point, build_time_info_args(&time_info_out_point, vec![
+            (ARGS_FIELD_TIME_INDEX_STATE_TYPE_HASH, state_type_script.calc_script_hash().as_bytes()),
+        ])).
+        expect("script");
+
+    // time index state moves to 0, and time info cell of index 0 is updated
+    let time_index = 0;
+    let last_state_index = TIME_INFO_CELL_DATA_N - 1;
+
+    let now = Utc::now().timestamp() as u64;
+
+    // prepare cells
+    let input_data = build_time_info_cell_data(time_index, now - TIME_INFO_UPDATE_INTERVAL, 0);
+    let time_info_input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(time_info_type_script.clone()).pack())
+            .build(),
+        input_data.clone(),
+    );
+    let state_input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(state_type_script.clone()).pack())
+            .build(),
+        build_time_index_state_cell_data(last_state_index, TIME_INFO_CELL_DATA_N),
+    );
+
+    let since: u64 = 1 << 62;
+    let inputs = vec![
+        CellInput::new_builder()
+            .previous_output(time_info_input_out_point)
+            .since((since + now).pack())
+            .build(),
+        CellInput::new_builder()
+            .previous_output(state_input_out_point)
+            .build(),
+    ];
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(time_info_type_script.clone()).pack())
+            .build(),
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(state_type_script.clone()).pack())
+            .build(),
+    ];
+    let outputs_data = vec![
+        build_next_time_info_cell_data(&input_data, time_index, now),
+        build_time_index_state_cell_data((last_state_index + 1) % TIME_INFO_CELL_DATA_N, TIME_INFO_CELL_DATA_N),
+    ];
+
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .inputs(inputs)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(time_info_script_dep)
+        .cell_dep(state_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_error_update_time_index_state_without_time_info_cell() {
+    // deploy contract
+    let mut context = Context::default();
+    let time_info_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let time_info_out_point = context.deploy_cell(time_info_bin);
+    let contract_bin: Bytes = Loader::default().load_binary("time_index_state_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    let time_info_family = build_time_info_family(&mut context, &time_info_out_point);
+    let type_script = context.
+        build_script(&out_point, build_time_info_args(&out_point, vec![
+            (ARGS_FIELD_TIME_INFO_FAMILY, time_info_family),
+        ])).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    // time index moves without updating time info cell of the family
+    let input_data = build_time_index_state_cell_data(TIME_INFO_CELL_DATA_N - 1, TIME_INFO_CELL_DATA_N);
+    let outputs_data = vec![build_time_index_state_cell_data(0, TIME_INFO_CELL_DATA_N)];
+
+    // prepare cells
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        input_data,
+    );
+
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .build();
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+    ];
+
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(STATE_ERROR_INVALID_TIME_INFO_UPDATE).input_type_script(0)
+    );
+}
+
+#[test]
+fn test_error_create_with_unconsumed_args_out_point() {
+    let mut context = Context::default();
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    // prepare cells
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+
+    // deploy contract
+    let contract_bin: Bytes = Loader::default().load_binary("time_index_state_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    // out point in args is not consumed by the transaction
+    let type_script = context.
+        build_script(&out_point, out_point.as_bytes()).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .build();
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(500u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+    ];
+
+    let outputs_data = vec![build_time_index_state_cell_data(0, TIME_INFO_CELL_DATA_N)];
+
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(STATE_ERROR_INVALID_ARGS_OUT_POINT).output_type_script(0)
+    );
+}
+
+#[test]
+fn test_error_update_time_index_state_without_operator() {
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("time_index_state_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let operator_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![1]))
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    let type_script = context.
+        build_script(&out_point, build_time_info_args(
+            &out_point,
+            vec![(ARGS_FIELD_OPERATOR_LOCK_HASH, operator_lock_script.calc_script_hash().as_bytes())],
+        )).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    // time index moves without an input with operator lock
+    let input_data = build_time_index_state_cell_data(TIME_INFO_CELL_DATA_N - 1, TIME_INFO_CELL_DATA_N);
+    let outputs_data = vec![build_time_index_state_cell_data(0, TIME_INFO_CELL_DATA_N)];
+
+    // prepare cells
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        input_data,
+    );
+
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .build();
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+    ];
+
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(STATE_ERROR_INVALID_OPERATOR_AUTHORIZATION).input_type_script(0)
+    );
+}
+
+#[test]
+fn test_error_destroy_time_index_state_without_admin_args() {
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("time_index_state_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    let type_script = context.
+        build_script(&out_point, out_point.as_bytes()).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    // prepare cells
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        build_time_index_state_cell_data(0, TIME_INFO_CELL_DATA_N),
+    );
+
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .build();
+    // time index state cell cannot be destroyed without admin lock hash in args
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .build(),
+    ];
+
+    let outputs_data = vec![Bytes::new()];
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(STATE_ERROR_DESTROY_NOT_ALLOWED).input_type_script(0)
+    );
+}
+
+#[test]
+fn test_error_destroy_time_index_state_without_admin_input() {
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("time_index_state_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let admin_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![1]))
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    let type_script = context.
+        build_script(&out_point, build_time_info_args(
+            &out_point,
+            vec![(ARGS_FIELD_ADMIN_LOCK_HASH, admin_lock_script.calc_script_hash().as_bytes())],
+        )).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    // prepare cells
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        build_time_index_state_cell_data(0, TIME_INFO_CELL_DATA_N),
+    );
+
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .build();
+    // time index state cell is destroyed without an input with admin lock
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .build(),
+    ];
+
+    let outputs_data = vec![Bytes::new()];
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(STATE_ERROR_INVALID_ADMIN_AUTHORIZATION).input_type_script(0)
+    );
+}
@@ -0,0 +1,237 @@
+use std::convert::TryFrom;
+
+use time_info_types::args::{
+    parse_time_info_args,
+    ARGS_FIELD_ORACLE_KEY,
+    ARGS_FIELD_ORACLE_QUORUM,
+    ARGS_FIELD_ORACLE_SPREAD,
+    ARGS_FIELD_SECP256K1_LIB_CODE_HASH,
+    ORACLE_KEY_TYPE_ED25519,
+    ORACLE_KEY_TYPE_SECP256K1,
+};
+use time_info_types::chain::{ckb_blake2b_256, verify_time_info_history, GENESIS_PREV_DATA_HASH};
+use time_info_types::data::{
+    encode_time_info_cell_data,
+    parse_active_time_info_data,
+    HeaderDepBlock,
+    TimeInfoData,
+    CELL_DATA_FLAG_HEADER_DEP_BLOCK,
+    CELL_DATA_FLAG_ORACLE_ENTRIES,
+    CELL_DATA_FLAG_PAUSED,
+    CELL_DATA_VERSION_0,
+    CELL_DATA_VERSION_1,
+    CELL_DATA_VERSION_2,
+};
+use time_info_types::error::ParseError;
+use time_info_types::oracle::{
+    median_timestamp,
+    parse_oracle_attestations,
+    OracleEntry,
+    ORACLE_ATTESTATION_LEN,
+};
+use time_info_types::state::TimeIndexStateData;
+
+#[test]
+fn test_decode_time_info_data() {
+    let cell_data = encode_time_info_cell_data(3, 1_600_000_000, 7, &[1; 32]);
+    let time_info_data = TimeInfoData::try_from(&cell_data[..]).expect("time info data");
+    assert_eq!(time_info_data, TimeInfoData::new(3, 1_600_000_000, 7, [1; 32]));
+
+    //version 2 has no sequence
+    let cell_data = [CELL_DATA_VERSION_2, 0, 3, 0, 0, 0, 0, 0x5f, 0x5e, 0x10, 0x00];
+    let time_info_data = TimeInfoData::try_from(&cell_data[..]).expect("time info data");
+    assert_eq!((time_info_data.version, time_info_data.sequence), (CELL_DATA_VERSION_2, None));
+}
+
+#[test]
+fn test_decode_legacy_time_info_data() {
+    let cell_data = [3_u8, 0x5f, 0x5e, 0x10, 0x00];
+    let time_info_data = TimeInfoData::try_from(&cell_data[..]).expect("time info data");
+    assert_eq!(time_info_data, TimeInfoData {
+        version: CELL_DATA_VERSION_0,
+        index: 3,
+        timestamp: 1_600_000_000,
+        sequence: None,
+        prev_data_hash: None,
+        oracle_entries: None,
+        header_dep_block: None,
+        paused: false,
+    });
+
+    let cell_data = [3_u8, 0, 0, 0, 0, 0x5f, 0x5e, 0x10, 0x00];
+    let time_info_data = TimeInfoData::try_from(&cell_data[..]).expect("time info data");
+    assert_eq!(time_info_data, TimeInfoData {
+        version: CELL_DATA_VERSION_1,
+        index: 3,
+        timestamp: 1_600_000_000,
+        sequence: None,
+        prev_data_hash: None,
+        oracle_entries: None,
+        header_dep_block: None,
+        paused: false,
+    });
+}
+
+#[test]
+fn test_decode_malformed_time_info_data() {
+    let cell_data = encode_time_info_cell_data(3, 1_600_000_000, 7, &[1; 32]);
+    assert_eq!(TimeInfoData::try_from(&cell_data[..0]), Err(ParseError::EmptyCellData));
+    assert_eq!(TimeInfoData::try_from(&cell_data[..50]), Err(ParseError::InvalidCellDataLength));
+    assert_eq!(TimeInfoData::try_from(&[9_u8, 0, 3][..]), Err(ParseError::UnknownCellDataVersion(9)));
+
+    let mut cell_data = cell_data;
+    cell_data[1] = 0x80;
+    assert_eq!(TimeInfoData::try_from(&cell_data[..]), Err(ParseError::InvalidCellDataFlags));
+}
+
+#[test]
+fn test_decode_time_index_state_data() {
+    assert_eq!(TimeIndexStateData::try_from(&[1_u8, 12][..]), Ok(TimeIndexStateData { index: 1, ring_size: 12 }));
+    assert_eq!(TimeIndexStateData::try_from(&[0_u8; 0][..]), Err(ParseError::EmptyCellData));
+    assert_eq!(TimeIndexStateData::try_from(&[1_u8, 12, 0][..]), Err(ParseError::InvalidCellDataLength));
+}
+
+fn build_oracle_quorum_args(threshold: u8, oracle_count: u8, extra_fields: &[u8]) -> Vec<u8> {
+    let mut args = vec![0_u8; 36];
+    args.extend_from_slice(&[ARGS_FIELD_SECP256K1_LIB_CODE_HASH, 32]);
+    args.extend_from_slice(&[1_u8; 32]);
+    args.extend_from_slice(&[ARGS_FIELD_ORACLE_QUORUM, 1 + 21 * oracle_count, threshold]);
+    for oracle in 0..oracle_count {
+        args.push(ORACLE_KEY_TYPE_SECP256K1);
+        args.extend_from_slice(&[oracle; 20]);
+    }
+    args.extend_from_slice(extra_fields);
+    args
+}
+
+#[test]
+fn test_parse_oracle_quorum_args() {
+    let time_info_args = parse_time_info_args(&build_oracle_quorum_args(2, 3, &[])).expect("time info args");
+    let oracle_quorum = time_info_args.oracle_quorum.expect("oracle quorum");
+    assert_eq!(oracle_quorum.threshold, 2);
+    assert_eq!(oracle_quorum.keys().len(), 3);
+    assert_eq!(oracle_quorum.keys()[2].pubkey_hash, [2_u8; 20]);
+
+    let spread = [ARGS_FIELD_ORACLE_SPREAD, 4, 0, 0, 0, 30];
+    let time_info_args = parse_time_info_args(&build_oracle_quorum_args(3, 3, &spread)).expect("time info args");
+    assert_eq!(time_info_args.oracle_spread, Some(30));
+
+    //threshold should be in [1, N]
+    assert_eq!(parse_time_info_args(&build_oracle_quorum_args(0, 3, &[])).err(), Some(ParseError::InvalidArgument));
+    assert_eq!(parse_time_info_args(&build_oracle_quorum_args(4, 3, &[])).err(), Some(ParseError::InvalidArgument));
+}
+
+#[test]
+fn test_parse_ed25519_oracle_key_args() {
+    //ed25519 oracle key needs no secp256k1 library
+    let mut args = vec![0_u8; 36];
+    args.extend_from_slice(&[ARGS_FIELD_ORACLE_KEY, 21, ORACLE_KEY_TYPE_ED25519]);
+    args.extend_from_slice(&[2_u8; 20]);
+    let time_info_args = parse_time_info_args(&args).expect("time info args");
+    let oracle_key = time_info_args.oracle_key.expect("oracle key");
+    assert_eq!(oracle_key.key_type, ORACLE_KEY_TYPE_ED25519);
+    assert_eq!(oracle_key.pubkey_hash, [2_u8; 20]);
+
+    //quorum attestations carry recoverable secp256k1 signatures only
+    let mut args = build_oracle_quorum_args(1, 2, &[]);
+    let last_key_type = args.len() - 21;
+    args[last_key_type] = ORACLE_KEY_TYPE_ED25519;
+    assert_eq!(parse_time_info_args(&args).err(), Some(ParseError::InvalidArgument));
+}
+
+#[test]
+fn test_parse_oracle_attestations() {
+    let mut witness = vec![0_u8; ORACLE_ATTESTATION_LEN * 2];
+    witness[ORACLE_ATTESTATION_LEN] = 2;
+    witness[ORACLE_ATTESTATION_LEN + 8] = 7;
+    let attestations: Vec<_> = match parse_oracle_attestations(&witness) {
+        Ok(attestations) => attestations.collect(),
+        Err(err) => panic!("invalid attestations: {:?}", err),
+    };
+    assert_eq!(attestations.len(), 2);
+    assert_eq!(attestations[1].oracle_index, 2);
+    assert_eq!(attestations[1].timestamp, 7);
+
+    //every oracle attests once
+    witness[ORACLE_ATTESTATION_LEN] = 0;
+    assert_eq!(parse_oracle_attestations(&witness).err(), Some(ParseError::InvalidOracleWitness));
+    assert_eq!(parse_oracle_attestations(&witness[1..]).err(), Some(ParseError::InvalidOracleWitness));
+    assert_eq!(parse_oracle_attestations(&[]).err(), Some(ParseError::InvalidOracleWitness));
+}
+
+#[test]
+fn test_median_timestamp() {
+    assert_eq!(median_timestamp(&mut [30, 10, 20]), Some(20));
+    assert_eq!(median_timestamp(&mut [40, 10, 30, 20]), Some(20));
+    assert_eq!(median_timestamp(&mut []), None);
+}
+
+fn encode_time_info_cell_data_with_oracle_entries(timestamp: u64, entries: &[(u8, u64)]) -> Vec<u8> {
+    let mut cell_data = encode_time_info_cell_data(3, timestamp, 0, &GENESIS_PREV_DATA_HASH).to_vec();
+    cell_data[1] = CELL_DATA_FLAG_ORACLE_ENTRIES;
+    cell_data.push(entries.len() as u8);
+    for (oracle_index, oracle_timestamp) in entries {
+        cell_data.push(*oracle_index);
+        cell_data.extend_from_slice(&oracle_timestamp.to_be_bytes());
+    }
+    cell_data
+}
+
+#[test]
+fn test_decode_time_info_data_with_oracle_entries() {
+    let cell_data = encode_time_info_cell_data_with_oracle_entries(20, &[(0, 30), (2, 10), (5, 20)]);
+    let time_info_data = TimeInfoData::try_from(&cell_data[..]).expect("time info data");
+    let oracle_entries = time_info_data.oracle_entries.expect("oracle entries");
+    assert_eq!(time_info_data.timestamp, 20);
+    assert_eq!(oracle_entries.entries()[1], OracleEntry { oracle_index: 2, timestamp: 10 });
+    assert_eq!(oracle_entries.median_timestamp(), Some(20));
+
+    //every oracle has one entry at most
+    let cell_data = encode_time_info_cell_data_with_oracle_entries(20, &[(2, 10), (2, 20)]);
+    assert_eq!(TimeInfoData::try_from(&cell_data[..]), Err(ParseError::InvalidOracleEntries));
+    let cell_data = encode_time_info_cell_data_with_oracle_entries(20, &[]);
+    assert_eq!(TimeInfoData::try_from(&cell_data[..]), Err(ParseError::InvalidOracleEntries));
+    let cell_data = encode_time_info_cell_data_with_oracle_entries(20, &[(0, 20)]);
+    assert_eq!(TimeInfoData::try_from(&cell_data[..cell_data.len() - 1]), Err(ParseError::InvalidCellDataLength));
+}
+
+#[test]
+fn test_verify_time_info_history() {
+    let genesis = encode_time_info_cell_data(3, 1_600_000_000, 0, &GENESIS_PREV_DATA_HASH);
+    let first = encode_time_info_cell_data(3, 1_600_000_060, 1, &ckb_blake2b_256(&genesis));
+    let head = encode_time_info_cell_data(3, 1_600_000_120, 2, &ckb_blake2b_256(&first));
+    assert!(verify_time_info_history(&[&genesis[..], &first[..]], &head));
+    assert!(verify_time_info_history(&[&first[..]], &head));
+
+    //every cell data of the history should be chained
+    assert!(!verify_time_info_history(&[&genesis[..]], &head));
+    assert!(!verify_time_info_history(&[&first[..], &genesis[..]], &head));
+    assert!(!verify_time_info_history(&[], &head));
+}
+
+#[test]
+fn test_decode_time_info_data_with_header_dep_block() {
+    let mut cell_data = encode_time_info_cell_data_with_oracle_entries(20, &[(0, 20)]);
+    cell_data[1] |= CELL_DATA_FLAG_HEADER_DEP_BLOCK;
+    cell_data.extend_from_slice(&1000_u64.to_be_bytes());
+    cell_data.extend_from_slice(&42_u64.to_be_bytes());
+    let time_info_data = TimeInfoData::try_from(&cell_data[..]).expect("time info data");
+    assert_eq!(time_info_data.header_dep_block, Some(HeaderDepBlock { block_number: 1000, epoch: 42 }));
+    assert_eq!(time_info_data.oracle_entries.expect("oracle entries").median_timestamp(), Some(20));
+
+    //optional fields should be exactly the ones in flags
+    assert_eq!(TimeInfoData::try_from(&cell_data[..cell_data.len() - 1]), Err(ParseError::InvalidCellDataLength));
+    cell_data[1] = CELL_DATA_FLAG_HEADER_DEP_BLOCK;
+    assert_eq!(TimeInfoData::try_from(&cell_data[..]), Err(ParseError::InvalidCellDataLength));
+}
+
+#[test]
+fn test_parse_active_time_info_data() {
+    let mut cell_data = encode_time_info_cell_data(3, 30, 1, &GENESIS_PREV_DATA_HASH).to_vec();
+    assert_eq!(parse_active_time_info_data(&cell_data).map(|data| data.timestamp), Ok(30));
+
+    //paused time info cell is still decodable, but consumers get an explicit error
+    cell_data[1] |= CELL_DATA_FLAG_PAUSED;
+    assert!(TimeInfoData::try_from(&cell_data[..]).expect("time info data").paused);
+    assert_eq!(parse_active_time_info_data(&cell_data), Err(ParseError::TimeInfoPaused));
+}
@@ -4,33 +4,181 @@ use ckb_tool::ckb_error::assert_error_eq;
 use ckb_tool::ckb_script::ScriptError;
 use ckb_tool::ckb_types::{
     bytes::{Bytes, BytesMut},
-    core::TransactionBuilder,
+    core::{HeaderBuilder, TransactionBuilder},
     packed::*,
     prelude::*,
 };
+use ckb_tool::ckb_crypto::secp::{Generator, Privkey};
+use ckb_tool::ckb_hash::blake2b_256;
 use ckb_tool::ckb_types::bytes::BufMut;
+use ring::rand::SystemRandom;
+use ring::signature::{Ed25519KeyPair, KeyPair};
+use time_info_types::args::{
+    TIME_INFO_CELL_DATA_N,
+    ARGS_FIELD_ADMIN_LOCK_HASH,
+    ARGS_FIELD_OPERATOR_LOCK_HASH,
+    ARGS_FIELD_MIN_UPDATE_INTERVAL,
+    ARGS_FIELD_MAX_TIMESTAMP_STEP,
+    ARGS_FIELD_HEADER_DEP_TOLERANCE,
+    ARGS_FIELD_SINCE_TOLERANCE,
+    ARGS_FIELD_TIME_INDEX_STATE_TYPE_HASH,
+    ARGS_FIELD_FEE_ALLOWANCE,
+    ARGS_FIELD_ORACLE_KEY,
+    ARGS_FIELD_SECP256K1_LIB_CODE_HASH,
+    ARGS_FIELD_ORACLE_QUORUM,
+    ARGS_FIELD_ORACLE_SPREAD,
+    ORACLE_KEY_TYPE_SECP256K1,
+    ORACLE_KEY_TYPE_ED25519,
+};
+use time_info_types::data::{CELL_DATA_LEN_0, CELL_DATA_LEN_1};
+use time_info_types::oracle::encode_oracle_message;
+use time_info_types::error::{
+    ERROR_INVALID_ARGUMENT,
+    ERROR_INVALID_CELL_DATA,
+    ERROR_INVALID_TIME_INFO_INPUT,
+    ERROR_INVALID_TIME_INFO_OUTPUT,
+    ERROR_INVALID_TIME_SINCE,
+    ERROR_INVALID_TIMESTAMP,
+    ERROR_INVALID_TIME_INDEX,
+    ERROR_INVALID_OPERATOR_AUTHORIZATION,
+    ERROR_INVALID_UPDATE_INTERVAL,
+    ERROR_INVALID_TIMESTAMP_STEP,
+    ERROR_INVALID_CELL_DATA_VERSION,
+    ERROR_INVALID_HEADER_DEP,
+    ERROR_INVALID_HEADER_DEP_TIMESTAMP,
+    ERROR_INVALID_SINCE_FLAGS,
+    ERROR_RELATIVE_SINCE,
+    ERROR_INVALID_TIME_INDEX_STATE,
+    ERROR_INVALID_CELL_LOCK,
+    ERROR_INVALID_CELL_CAPACITY,
+    ERROR_INVALID_ORACLE_LIB,
+    ERROR_INVALID_ORACLE_WITNESS,
+    ERROR_INVALID_ORACLE_SIGNATURE,
+    ERROR_INVALID_ORACLE_QUORUM,
+    ERROR_INVALID_ORACLE_TIMESTAMP,
+    ERROR_INVALID_ORACLE_ENTRIES,
+    ERROR_INVALID_SEQUENCE,
+    ERROR_INVALID_PREV_DATA_HASH,
+    ERROR_INVALID_HEADER_DEP_BLOCK,
+    ERROR_INVALID_ADMIN_AUTHORIZATION,
+    ERROR_TIME_INFO_PAUSED,
+};
 
 use super::*;
+use crate::helper::{
+    MAX_CYCLES,
+    TIME_INFO_UPDATE_INTERVAL,
+    build_time_info_cell_data,
+    build_next_time_info_cell_data,
+    build_paused_time_info_cell_data,
+    build_time_info_cell_data_with_oracle_entries,
+    build_time_info_cell_data_with_header_dep_block,
+    build_time_info_args,
+    load_secp256k1_lib,
+};
+
+fn build_oracle_key(key_type: u8) -> Bytes {
+    let mut key_buf = BytesMut::with_capacity(21);
+    key_buf.put_u8(key_type);
+    key_buf.put(&[2u8; 20][..]);
+    Bytes::from(key_buf.to_vec())
+}
+
+fn build_oracle_quorum(threshold: u8, oracle_count: u8) -> Bytes {
+    let mut quorum_buf = BytesMut::with_capacity(1 + 21 * oracle_count as usize);
+    quorum_buf.put_u8(threshold);
+    for oracle in 0..oracle_count {
+        quorum_buf.put_u8(ORACLE_KEY_TYPE_SECP256K1);
+        quorum_buf.put(&[oracle; 20][..]);
+    }
+    Bytes::from(quorum_buf.to_vec())
+}
+
+fn build_secp256k1_oracle_key(privkey: &Privkey) -> Bytes {
+    let pubkey = privkey.pubkey().expect("pubkey");
+    let mut key_buf = BytesMut::with_capacity(21);
+    key_buf.put_u8(ORACLE_KEY_TYPE_SECP256K1);
+    key_buf.put(&blake2b_256(pubkey.serialize())[..20]);
+    Bytes::from(key_buf.to_vec())
+}
+
+//oracle signs blake2b(index | timestamp | previous out point) of the update
+fn sign_oracle_timestamp(privkey: &Privkey, time_index: u8, timestamp: u64, previous_out_point: &OutPoint) -> Bytes {
+    let mut out_point = [0u8; 36];
+    out_point.copy_from_slice(previous_out_point.as_slice());
+    let message = blake2b_256(&encode_oracle_message(time_index, timestamp, &out_point)[..]);
+    let signature = privkey.sign_recoverable(&message.into()).expect("sign");
+    Bytes::from(signature.serialize())
+}
+
+fn random_ed25519_key_pair() -> Ed25519KeyPair {
+    let pkcs8 = Ed25519KeyPair::generate_pkcs8(&SystemRandom::new()).expect("pkcs8");
+    Ed25519KeyPair::from_pkcs8(pkcs8.as_ref()).expect("key pair")
+}
+
+fn build_ed25519_oracle_key(key_pair: &Ed25519KeyPair) -> Bytes {
+    let mut key_buf = BytesMut::with_capacity(21);
+    key_buf.put_u8(ORACLE_KEY_TYPE_ED25519);
+    key_buf.put(&blake2b_256(key_pair.public_key().as_ref())[..20]);
+    Bytes::from(key_buf.to_vec())
+}
+
+//ed25519 pubkey can not be recovered from signature, so witness is pubkey | signature
+fn sign_ed25519_oracle_timestamp(
+    key_pair: &Ed25519KeyPair,
+    time_index: u8,
+    timestamp: u64,
+    previous_out_point: &OutPoint,
+) -> Bytes {
+    let mut out_point = [0u8; 36];
+    out_point.copy_from_slice(previous_out_point.as_slice());
+    let message = blake2b_256(&encode_oracle_message(time_index, timestamp, &out_point)[..]);
+    let mut witness_buf = BytesMut::with_capacity(96);
+    witness_buf.put_slice(key_pair.public_key().as_ref());
+    witness_buf.put_slice(key_pair.sign(&message).as_ref());
+    Bytes::from(witness_buf.to_vec())
+}
 
-const ERROR_TIME_INFO_ARGS: i8 = 54;
-const ERROR_TIME_INFO_CELL_DATA: i8 = 55;
-const ERROR_TIME_INFO_INPUT: i8 = 56;
-const ERROR_TIME_INFO_OUTPUT: i8 = 57;
-const ERROR_TIME_INFO_SINCE: i8 = 58;
-const ERROR_TIME_INFO_TIMESTAMP: i8 = 59;
-const ERROR_TIME_INFO_TIME_INDEX: i8 = 60;
+fn build_secp256k1_oracle_quorum(threshold: u8, oracle_privkeys: &[Privkey]) -> Bytes {
+    let mut quorum_buf = BytesMut::with_capacity(1 + 21 * oracle_privkeys.len());
+    quorum_buf.put_u8(threshold);
+    for privkey in oracle_privkeys {
+        quorum_buf.put_slice(&build_secp256k1_oracle_key(privkey));
+    }
+    Bytes::from(quorum_buf.to_vec())
+}
 
-const MAX_CYCLES: u64 = 10_000_000;
-const TIME_INFO_CELL_DATA_LEN: usize = 5;
-const TIME_INFO_UPDATE_INTERVAL: u32 = 60;
+//every oracle of quorum signs its own timestamp, attestations are ordered by oracle index
+fn build_oracle_attestations(
+    oracle_privkeys: &[Privkey],
+    time_index: u8,
+    previous_out_point: &OutPoint,
+    timestamps: Vec<(u8, u64)>,
+) -> Bytes {
+    let mut witness_buf = BytesMut::new();
+    for (oracle_index, oracle_timestamp) in timestamps {
+        let privkey = &oracle_privkeys[oracle_index as usize];
+        witness_buf.put_u8(oracle_index);
+        witness_buf.put_u64(oracle_timestamp);
+        witness_buf.put_slice(&sign_oracle_timestamp(privkey, time_index, oracle_timestamp, previous_out_point));
+    }
+    Bytes::from(witness_buf.to_vec())
+}
 
-fn build_time_info_cell_data(index: u8, timestamp: u32) -> Bytes {
-    let mut time_buf = BytesMut::with_capacity(TIME_INFO_CELL_DATA_LEN);
+fn build_legacy_time_info_cell_data(index: u8, timestamp: u32) -> Bytes {
+    let mut time_buf = BytesMut::with_capacity(CELL_DATA_LEN_0);
     time_buf.put_u8(index);
     time_buf.put_u32(timestamp);
     Bytes::from(time_buf.to_vec())
 }
 
+fn build_legacy_u64_time_info_cell_data(index: u8, timestamp: u64) -> Bytes {
+    let mut time_buf = BytesMut::with_capacity(CELL_DATA_LEN_1);
+    time_buf.put_u8(index);
+    time_buf.put_u64(timestamp);
+    Bytes::from(time_buf.to_vec())
+}
+
 #[test]
 fn test_success_update() {
     // deploy contract
@@ -54,23 +202,24 @@ fn test_success_update() {
         .out_point(always_success_out_point)
         .build();
 
-    let now = Utc::now().timestamp() as u32;
+    let now = Utc::now().timestamp() as u64;
     let time_index = 0;
 
     // prepare cells
+    let input_data = build_time_info_cell_data(time_index, now - TIME_INFO_UPDATE_INTERVAL, 0);
     let input_out_point = context.create_cell(
         CellOutput::new_builder()
             .capacity(1000u64.pack())
             .lock(lock_script.clone())
             .type_(Some(type_script.clone()).pack())
             .build(),
-        build_time_info_cell_data(time_index, now - TIME_INFO_UPDATE_INTERVAL),
+        input_data.clone(),
     );
 
     let since: u64 = 1 << 62;
     let input = CellInput::new_builder()
         .previous_output(input_out_point)
-        .since((since + now as u64).pack())
+        .since((since + now).pack())
         .build();
     let outputs = vec![
         CellOutput::new_builder()
@@ -80,7 +229,7 @@ fn test_success_update() {
             .build(),
     ];
 
-    let outputs_data = vec![build_time_info_cell_data(time_index, now)];
+    let outputs_data = vec![build_next_time_info_cell_data(&input_data, time_index, now)];
     // build transaction
     let tx = TransactionBuilder::default()
         .input(input)
@@ -121,28 +270,29 @@ fn test_error_invalid_input() {
         .out_point(always_success_out_point)
         .build();
 
-    let now = Utc::now().timestamp() as u32;
+    let now = Utc::now().timestamp() as u64;
     let time_index = 0;
 
     // prepare cells
+    let input_data = build_time_info_cell_data(time_index, now - TIME_INFO_UPDATE_INTERVAL, 0);
     let input_out_point = context.create_cell(
         CellOutput::new_builder()
             .capacity(1000u64.pack())
             .lock(lock_script.clone())
             .type_(Some(type_script.clone()).pack())
             .build(),
-        build_time_info_cell_data(time_index, now - TIME_INFO_UPDATE_INTERVAL),
+        input_data.clone(),
     );
 
     let since: u64 = 1 << 62;
     let intpus = vec![
         CellInput::new_builder()
             .previous_output(input_out_point.clone())
-            .since((since + now as u64).pack())
+            .since((since + now).pack())
             .build(),
         CellInput::new_builder()
             .previous_output(input_out_point.clone())
-            .since((since + now as u64).pack())
+            .since((since + now).pack())
             .build()
     ];
     let outputs = vec![
@@ -153,7 +303,7 @@ fn test_error_invalid_input() {
             .build(),
     ];
 
-    let outputs_data = vec![build_time_info_cell_data(time_index, now)];
+    let outputs_data = vec![build_next_time_info_cell_data(&input_data, time_index, now)];
     // build transaction
     let tx = TransactionBuilder::default()
         .inputs(intpus)
@@ -168,7 +318,7 @@ fn test_error_invalid_input() {
     let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
     assert_error_eq!(
         err,
-        ScriptError::ValidationFailure(ERROR_TIME_INFO_INPUT).input_type_script(0)
+        ScriptError::ValidationFailure(ERROR_INVALID_TIME_INFO_INPUT).input_type_script(0)
     );
 }
 
@@ -195,24 +345,25 @@ fn test_error_invalid_output() {
         .out_point(always_success_out_point)
         .build();
 
-    let now = Utc::now().timestamp() as u32;
+    let now = Utc::now().timestamp() as u64;
     let time_index = 0;
 
     // prepare cells
+    let input_data = build_time_info_cell_data(time_index, now - TIME_INFO_UPDATE_INTERVAL, 0);
     let input_out_point = context.create_cell(
         CellOutput::new_builder()
             .capacity(1000u64.pack())
             .lock(lock_script.clone())
             .type_(Some(type_script.clone()).pack())
             .build(),
-        build_time_info_cell_data(time_index, now - TIME_INFO_UPDATE_INTERVAL),
+        input_data.clone(),
     );
 
     let since: u64 = 1 << 62;
     let intpus = vec![
         CellInput::new_builder()
             .previous_output(input_out_point.clone())
-            .since((since + now as u64).pack())
+            .since((since + now).pack())
             .build(),
     ];
     let outputs = vec![
@@ -229,8 +380,8 @@ fn test_error_invalid_output() {
     ];
 
     let outputs_data = vec![
-        build_time_info_cell_data(time_index, now),
-        build_time_info_cell_data(time_index, now)];
+        build_next_time_info_cell_data(&input_data, time_index, now),
+        build_next_time_info_cell_data(&input_data, time_index, now)];
     // build transaction
     let tx = TransactionBuilder::default()
         .inputs(intpus)
@@ -245,7 +396,7 @@ fn test_error_invalid_output() {
     let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
     assert_error_eq!(
         err,
-        ScriptError::ValidationFailure(ERROR_TIME_INFO_OUTPUT).input_type_script(0)
+        ScriptError::ValidationFailure(ERROR_INVALID_TIME_INFO_OUTPUT).input_type_script(0)
     );
 }
 
@@ -272,24 +423,25 @@ fn test_error_invalid_cell_data() {
         .out_point(always_success_out_point)
         .build();
 
-    let now = Utc::now().timestamp() as u32;
+    let now = Utc::now().timestamp() as u64;
     let time_index = 0;
 
     // prepare cells
+    let input_data = build_time_info_cell_data(time_index, now - TIME_INFO_UPDATE_INTERVAL, 0);
     let input_out_point = context.create_cell(
         CellOutput::new_builder()
             .capacity(1000u64.pack())
             .lock(lock_script.clone())
             .type_(Some(type_script.clone()).pack())
             .build(),
-        build_time_info_cell_data(time_index, now - TIME_INFO_UPDATE_INTERVAL),
+        input_data.clone(),
     );
 
     let since: u64 = 1 << 62;
     let intpus = vec![
         CellInput::new_builder()
             .previous_output(input_out_point.clone())
-            .since((since + now as u64).pack())
+            .since((since + now).pack())
             .build(),
     ];
     let outputs = vec![
@@ -315,7 +467,7 @@ fn test_error_invalid_cell_data() {
     let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
     assert_error_eq!(
         err,
-        ScriptError::ValidationFailure(ERROR_TIME_INFO_CELL_DATA).input_type_script(0)
+        ScriptError::ValidationFailure(ERROR_INVALID_CELL_DATA).input_type_script(0)
     );
 }
 
@@ -342,17 +494,18 @@ fn test_error_invalid_since() {
         .out_point(always_success_out_point)
         .build();
 
-    let now = Utc::now().timestamp() as u32;
+    let now = Utc::now().timestamp() as u64;
     let time_index = 0;
 
     // prepare cells
+    let input_data = build_time_info_cell_data(time_index, now - TIME_INFO_UPDATE_INTERVAL, 0);
     let input_out_point = context.create_cell(
         CellOutput::new_builder()
             .capacity(1000u64.pack())
             .lock(lock_script.clone())
             .type_(Some(type_script.clone()).pack())
             .build(),
-        build_time_info_cell_data(time_index, now - TIME_INFO_UPDATE_INTERVAL),
+        input_data.clone(),
     );
 
     let input = CellInput::new_builder()
@@ -366,7 +519,7 @@ fn test_error_invalid_since() {
             .build(),
     ];
 
-    let outputs_data = vec![build_time_info_cell_data(time_index, now)];
+    let outputs_data = vec![build_next_time_info_cell_data(&input_data, time_index, now)];
     // build transaction
     let tx = TransactionBuilder::default()
         .input(input)
@@ -381,7 +534,7 @@ fn test_error_invalid_since() {
     let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
     assert_error_eq!(
         err,
-        ScriptError::ValidationFailure(ERROR_TIME_INFO_SINCE).input_type_script(0)
+        ScriptError::ValidationFailure(ERROR_INVALID_TIME_SINCE).input_type_script(0)
     );
 }
 
@@ -408,23 +561,24 @@ fn test_error_invalid_timestamp() {
         .out_point(always_success_out_point)
         .build();
 
-    let now = Utc::now().timestamp() as u32;
+    let now = Utc::now().timestamp() as u64;
     let time_index = 0;
 
     // prepare cells
+    let input_data = build_time_info_cell_data(time_index, now + TIME_INFO_UPDATE_INTERVAL, 0);
     let input_out_point = context.create_cell(
         CellOutput::new_builder()
             .capacity(1000u64.pack())
             .lock(lock_script.clone())
             .type_(Some(type_script.clone()).pack())
             .build(),
-        build_time_info_cell_data(time_index, now + TIME_INFO_UPDATE_INTERVAL),
+        input_data.clone(),
     );
 
     let since: u64 = 1 << 62;
     let input = CellInput::new_builder()
         .previous_output(input_out_point)
-        .since((since + now as u64).pack())
+        .since((since + now).pack())
         .build();
     let outputs = vec![
         CellOutput::new_builder()
@@ -434,7 +588,7 @@ fn test_error_invalid_timestamp() {
             .build(),
     ];
 
-    let outputs_data = vec![build_time_info_cell_data(time_index, now)];
+    let outputs_data = vec![build_next_time_info_cell_data(&input_data, time_index, now)];
     // build transaction
     let tx = TransactionBuilder::default()
         .input(input)
@@ -449,7 +603,7 @@ fn test_error_invalid_timestamp() {
     let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
     assert_error_eq!(
         err,
-        ScriptError::ValidationFailure(ERROR_TIME_INFO_TIMESTAMP).input_type_script(0)
+        ScriptError::ValidationFailure(ERROR_INVALID_TIMESTAMP).input_type_script(0)
     );
 }
 
@@ -476,23 +630,24 @@ fn test_error_invalid_time_index() {
         .out_point(always_success_out_point)
         .build();
 
-    let now = Utc::now().timestamp() as u32;
+    let now = Utc::now().timestamp() as u64;
     let time_index = 0;
 
     // prepare cells
+    let input_data = build_time_info_cell_data(time_index + 1, now - TIME_INFO_UPDATE_INTERVAL, 0);
     let input_out_point = context.create_cell(
         CellOutput::new_builder()
             .capacity(1000u64.pack())
             .lock(lock_script.clone())
             .type_(Some(type_script.clone()).pack())
             .build(),
-        build_time_info_cell_data(time_index + 1, now - TIME_INFO_UPDATE_INTERVAL),
+        input_data.clone(),
     );
 
     let since: u64 = 1 << 62;
     let input = CellInput::new_builder()
         .previous_output(input_out_point)
-        .since((since + now as u64).pack())
+        .since((since + now).pack())
         .build();
     let outputs = vec![
         CellOutput::new_builder()
@@ -502,7 +657,7 @@ fn test_error_invalid_time_index() {
             .build(),
     ];
 
-    let outputs_data = vec![build_time_info_cell_data(time_index, now)];
+    let outputs_data = vec![build_next_time_info_cell_data(&input_data, time_index, now)];
     // build transaction
     let tx = TransactionBuilder::default()
         .input(input)
@@ -517,7 +672,7 @@ fn test_error_invalid_time_index() {
     let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
     assert_error_eq!(
         err,
-        ScriptError::ValidationFailure(ERROR_TIME_INFO_TIME_INDEX).input_type_script(0)
+        ScriptError::ValidationFailure(ERROR_INVALID_TIME_INDEX).input_type_script(0)
     );
 }
 
@@ -546,23 +701,24 @@ fn test_error_empty_args() {
         .out_point(always_success_out_point)
         .build();
 
-    let now = Utc::now().timestamp() as u32;
+    let now = Utc::now().timestamp() as u64;
     let time_index = 0;
 
     // prepare cells
+    let input_data = build_time_info_cell_data(time_index, now - TIME_INFO_UPDATE_INTERVAL, 0);
     let input_out_point = context.create_cell(
         CellOutput::new_builder()
             .capacity(1000u64.pack())
             .lock(lock_script.clone())
             .type_(Some(type_script.clone()).pack())
             .build(),
-        build_time_info_cell_data(time_index, now - TIME_INFO_UPDATE_INTERVAL),
+        input_data.clone(),
     );
 
     let since: u64 = 1 << 62;
     let input = CellInput::new_builder()
         .previous_output(input_out_point)
-        .since((since + now as u64).pack())
+        .since((since + now).pack())
         .build();
     let outputs = vec![
         CellOutput::new_builder()
@@ -572,7 +728,173 @@ fn test_error_empty_args() {
             .build(),
     ];
 
-    let outputs_data = vec![build_time_info_cell_data(time_index, now)];
+    let outputs_data = vec![build_next_time_info_cell_data(&input_data, time_index, now)];
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(ERROR_INVALID_ARGUMENT).input_type_script(0)
+    );
+}
+#[test]
+fn test_success_update_with_operator() {
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let operator_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![1]))
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    let type_script = context.
+        build_script(&out_point, build_time_info_args(
+            &out_point,
+            vec![(ARGS_FIELD_OPERATOR_LOCK_HASH, operator_lock_script.calc_script_hash().as_bytes())],
+        )).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    let now = Utc::now().timestamp() as u64;
+    let time_index = 0;
+
+    // prepare cells
+    let input_data = build_time_info_cell_data(time_index, now - TIME_INFO_UPDATE_INTERVAL, 0);
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        input_data.clone(),
+    );
+    let operator_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(operator_lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+
+    let since: u64 = 1 << 62;
+    let inputs = vec![
+        CellInput::new_builder()
+            .previous_output(input_out_point)
+            .since((since + now).pack())
+            .build(),
+        CellInput::new_builder()
+            .previous_output(operator_out_point)
+            .build(),
+    ];
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        CellOutput::new_builder()
+            .capacity(500u64.pack())
+            .lock(operator_lock_script.clone())
+            .build(),
+    ];
+
+    let outputs_data = vec![build_next_time_info_cell_data(&input_data, time_index, now), Bytes::new()];
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .inputs(inputs)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_error_update_without_operator() {
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let operator_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![1]))
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    let type_script = context.
+        build_script(&out_point, build_time_info_args(
+            &out_point,
+            vec![(ARGS_FIELD_OPERATOR_LOCK_HASH, operator_lock_script.calc_script_hash().as_bytes())],
+        )).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    let now = Utc::now().timestamp() as u64;
+    let time_index = 0;
+
+    // prepare cells
+    let input_data = build_time_info_cell_data(time_index, now - TIME_INFO_UPDATE_INTERVAL, 0);
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        input_data.clone(),
+    );
+
+    let since: u64 = 1 << 62;
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .since((since + now).pack())
+        .build();
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+    ];
+
+    let outputs_data = vec![build_next_time_info_cell_data(&input_data, time_index, now)];
     // build transaction
     let tx = TransactionBuilder::default()
         .input(input)
@@ -587,6 +909,4494 @@ fn test_error_empty_args() {
     let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
     assert_error_eq!(
         err,
-        ScriptError::ValidationFailure(ERROR_TIME_INFO_ARGS).input_type_script(0)
+        ScriptError::ValidationFailure(ERROR_INVALID_OPERATOR_AUTHORIZATION).input_type_script(0)
+    );
+}
+
+#[test]
+fn test_success_update_with_min_interval() {
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    let type_script = context.
+        build_script(
+            &out_point,
+            build_time_info_args(
+                &out_point,
+                vec![(ARGS_FIELD_MIN_UPDATE_INTERVAL, Bytes::from(10u32.to_be_bytes().to_vec()))],
+            ),
+        ).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    let now = Utc::now().timestamp() as u64;
+    let time_index = 0;
+
+    // prepare cells
+    let input_data = build_time_info_cell_data(time_index, now - 10, 0);
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        input_data.clone(),
     );
-}
\ No newline at end of file
+
+    let since: u64 = 1 << 62;
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .since((since + now).pack())
+        .build();
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(500u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+    ];
+
+    let outputs_data = vec![build_next_time_info_cell_data(&input_data, time_index, now)];
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_error_update_too_frequently() {
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    let type_script = context.
+        build_script(&out_point, out_point.as_bytes()).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    let now = Utc::now().timestamp() as u64;
+    let time_index = 0;
+
+    // prepare cells
+    let input_data = build_time_info_cell_data(time_index, now - TIME_INFO_UPDATE_INTERVAL / 2, 0);
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        input_data.clone(),
+    );
+
+    let since: u64 = 1 << 62;
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .since((since + now).pack())
+        .build();
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(500u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+    ];
+
+    let outputs_data = vec![build_next_time_info_cell_data(&input_data, time_index, now)];
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(ERROR_INVALID_UPDATE_INTERVAL).input_type_script(0)
+    );
+}
+
+#[test]
+fn test_success_update_within_max_step() {
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    let max_step: u32 = 120;
+    let type_script = context.
+        build_script(
+            &out_point,
+            build_time_info_args(
+                &out_point,
+                vec![(ARGS_FIELD_MAX_TIMESTAMP_STEP, Bytes::from(max_step.to_be_bytes().to_vec()))],
+            ),
+        ).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    let now = Utc::now().timestamp() as u64;
+    let time_index = 0;
+
+    // prepare cells
+    let input_data = build_time_info_cell_data(time_index, now - max_step as u64, 0);
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        input_data.clone(),
+    );
+
+    let since: u64 = 1 << 62;
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .since((since + now).pack())
+        .build();
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(500u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+    ];
+
+    let outputs_data = vec![build_next_time_info_cell_data(&input_data, time_index, now)];
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_error_update_exceed_max_step() {
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    let max_step: u32 = 120;
+    let type_script = context.
+        build_script(
+            &out_point,
+            build_time_info_args(
+                &out_point,
+                vec![(ARGS_FIELD_MAX_TIMESTAMP_STEP, Bytes::from(max_step.to_be_bytes().to_vec()))],
+            ),
+        ).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    let now = Utc::now().timestamp() as u64;
+    let time_index = 0;
+
+    // prepare cells
+    let input_data = build_time_info_cell_data(time_index, now - (max_step + 1) as u64, 0);
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        input_data.clone(),
+    );
+
+    let since: u64 = 1 << 62;
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .since((since + now).pack())
+        .build();
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(500u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+    ];
+
+    let outputs_data = vec![build_next_time_info_cell_data(&input_data, time_index, now)];
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(ERROR_INVALID_TIMESTAMP_STEP).input_type_script(0)
+    );
+}
+
+#[test]
+fn test_success_update_legacy_cell_data() {
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    let type_script = context.
+        build_script(&out_point, out_point.as_bytes()).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    let now = Utc::now().timestamp() as u32;
+    let time_index = 0;
+
+    // prepare cells with legacy cell data
+    let input_data = build_legacy_time_info_cell_data(time_index, now - TIME_INFO_UPDATE_INTERVAL as u32);
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        input_data.clone(),
+    );
+
+    let since: u64 = 1 << 62;
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .since((since + now as u64).pack())
+        .build();
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(500u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+    ];
+
+    let outputs_data = vec![build_legacy_time_info_cell_data(time_index, now)];
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_success_update_migrate_cell_data() {
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    let type_script = context.
+        build_script(&out_point, out_point.as_bytes()).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    let now = Utc::now().timestamp() as u32;
+    let time_index = 0;
+
+    // prepare cells with legacy cell data
+    let input_data = build_legacy_time_info_cell_data(time_index, now - TIME_INFO_UPDATE_INTERVAL as u32);
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        input_data.clone(),
+    );
+
+    let since: u64 = 1 << 62;
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .since((since + now as u64).pack())
+        .build();
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(500u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+    ];
+
+    let outputs_data = vec![build_next_time_info_cell_data(&input_data, time_index, now as u64)];
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_error_update_downgrade_cell_data_version() {
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    let type_script = context.
+        build_script(&out_point, out_point.as_bytes()).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    let now = Utc::now().timestamp() as u64;
+    let time_index = 0;
+
+    // prepare cells
+    let input_data = build_time_info_cell_data(time_index, now - TIME_INFO_UPDATE_INTERVAL, 0);
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        input_data.clone(),
+    );
+
+    let since: u64 = 1 << 62;
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .since((since + now).pack())
+        .build();
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(500u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+    ];
+
+    // there is no migration from the latest version to legacy version
+    let outputs_data = vec![build_legacy_u64_time_info_cell_data(time_index, now)];
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(ERROR_INVALID_CELL_DATA_VERSION).input_type_script(0)
+    );
+}
+
+#[test]
+fn test_success_update_with_header_dep() {
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    let tolerance: u32 = 30;
+    let type_script = context.
+        build_script(
+            &out_point,
+            build_time_info_args(
+                &out_point,
+                vec![(ARGS_FIELD_HEADER_DEP_TOLERANCE, Bytes::from(tolerance.to_be_bytes().to_vec()))],
+            ),
+        ).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    let now = Utc::now().timestamp() as u64;
+    let time_index = 0;
+
+    // prepare header, the timestamp of header is in milliseconds
+    let header = HeaderBuilder::default()
+        .timestamp(((now - tolerance as u64) * 1000).pack())
+        .build();
+    context.insert_header(header.clone());
+
+    // prepare cells
+    let input_data = build_time_info_cell_data(time_index, now - TIME_INFO_UPDATE_INTERVAL, 0);
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        input_data.clone(),
+    );
+
+    let since: u64 = 1 << 62;
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .since((since + now).pack())
+        .build();
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(500u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+    ];
+
+    let outputs_data = vec![build_next_time_info_cell_data(&input_data, time_index, now)];
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .header_dep(header.hash())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_error_update_without_header_dep() {
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    let tolerance: u32 = 30;
+    let type_script = context.
+        build_script(
+            &out_point,
+            build_time_info_args(
+                &out_point,
+                vec![(ARGS_FIELD_HEADER_DEP_TOLERANCE, Bytes::from(tolerance.to_be_bytes().to_vec()))],
+            ),
+        ).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    let now = Utc::now().timestamp() as u64;
+    let time_index = 0;
+
+    // prepare header, the timestamp of header is in milliseconds
+    let header = HeaderBuilder::default()
+        .timestamp(((now) * 1000).pack())
+        .build();
+    context.insert_header(header.clone());
+
+    // prepare cells
+    let input_data = build_time_info_cell_data(time_index, now - TIME_INFO_UPDATE_INTERVAL, 0);
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        input_data.clone(),
+    );
+
+    let since: u64 = 1 << 62;
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .since((since + now).pack())
+        .build();
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(500u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+    ];
+
+    let outputs_data = vec![build_next_time_info_cell_data(&input_data, time_index, now)];
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(ERROR_INVALID_HEADER_DEP).input_type_script(0)
+    );
+}
+
+#[test]
+fn test_error_update_far_from_header_dep() {
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    let tolerance: u32 = 30;
+    let type_script = context.
+        build_script(
+            &out_point,
+            build_time_info_args(
+                &out_point,
+                vec![(ARGS_FIELD_HEADER_DEP_TOLERANCE, Bytes::from(tolerance.to_be_bytes().to_vec()))],
+            ),
+        ).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    let now = Utc::now().timestamp() as u64;
+    let time_index = 0;
+
+    // prepare header, the timestamp of header is in milliseconds
+    let header = HeaderBuilder::default()
+        .timestamp(((now - tolerance as u64 - 1) * 1000).pack())
+        .build();
+    context.insert_header(header.clone());
+
+    // prepare cells
+    let input_data = build_time_info_cell_data(time_index, now - TIME_INFO_UPDATE_INTERVAL, 0);
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        input_data.clone(),
+    );
+
+    let since: u64 = 1 << 62;
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .since((since + now).pack())
+        .build();
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(500u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+    ];
+
+    let outputs_data = vec![build_next_time_info_cell_data(&input_data, time_index, now)];
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .header_dep(header.hash())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(ERROR_INVALID_HEADER_DEP_TIMESTAMP).input_type_script(0)
+    );
+}
+
+#[test]
+fn test_success_update_with_since_tolerance() {
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    let tolerance: u32 = 30;
+    let type_script = context.
+        build_script(
+            &out_point,
+            build_time_info_args(
+                &out_point,
+                vec![(ARGS_FIELD_SINCE_TOLERANCE, Bytes::from(tolerance.to_be_bytes().to_vec()))],
+            ),
+        ).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    let now = Utc::now().timestamp() as u64;
+    let time_index = 0;
+
+    // prepare cells
+    let input_data = build_time_info_cell_data(time_index, now - TIME_INFO_UPDATE_INTERVAL, 0);
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        input_data.clone(),
+    );
+
+    // since is a little earlier than the new timestamp
+    let since: u64 = (1 << 62) + now - tolerance as u64;
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .since(since.pack())
+        .build();
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(500u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+    ];
+
+    let outputs_data = vec![build_next_time_info_cell_data(&input_data, time_index, now)];
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_error_update_since_out_of_tolerance() {
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    let tolerance: u32 = 30;
+    let type_script = context.
+        build_script(
+            &out_point,
+            build_time_info_args(
+                &out_point,
+                vec![(ARGS_FIELD_SINCE_TOLERANCE, Bytes::from(tolerance.to_be_bytes().to_vec()))],
+            ),
+        ).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    let now = Utc::now().timestamp() as u64;
+    let time_index = 0;
+
+    // prepare cells
+    let input_data = build_time_info_cell_data(time_index, now - TIME_INFO_UPDATE_INTERVAL, 0);
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        input_data.clone(),
+    );
+
+    let since: u64 = (1 << 62) + now - tolerance as u64 - 1;
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .since(since.pack())
+        .build();
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(500u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+    ];
+
+    let outputs_data = vec![build_next_time_info_cell_data(&input_data, time_index, now)];
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(ERROR_INVALID_TIME_SINCE).input_type_script(0)
+    );
+}
+
+#[test]
+fn test_error_update_with_malformed_since() {
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    let tolerance: u32 = 30;
+    let type_script = context.
+        build_script(
+            &out_point,
+            build_time_info_args(
+                &out_point,
+                vec![(ARGS_FIELD_SINCE_TOLERANCE, Bytes::from(tolerance.to_be_bytes().to_vec()))],
+            ),
+        ).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    let now = Utc::now().timestamp() as u64;
+    let time_index = 0;
+
+    // prepare cells
+    let input_data = build_time_info_cell_data(time_index, now - TIME_INFO_UPDATE_INTERVAL, 0);
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        input_data.clone(),
+    );
+
+    // reserved bits of since flags are set
+    let since: u64 = (1 << 62) + (1 << 56) + now;
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .since(since.pack())
+        .build();
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(500u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+    ];
+
+    let outputs_data = vec![build_next_time_info_cell_data(&input_data, time_index, now)];
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(ERROR_INVALID_SINCE_FLAGS).input_type_script(0)
+    );
+}
+
+#[test]
+fn test_error_update_with_relative_since() {
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    let tolerance: u32 = 30;
+    let type_script = context.
+        build_script(
+            &out_point,
+            build_time_info_args(
+                &out_point,
+                vec![(ARGS_FIELD_SINCE_TOLERANCE, Bytes::from(tolerance.to_be_bytes().to_vec()))],
+            ),
+        ).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    let now = Utc::now().timestamp() as u64;
+    let time_index = 0;
+
+    // prepare cells
+    let input_data = build_time_info_cell_data(time_index, now - TIME_INFO_UPDATE_INTERVAL, 0);
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        input_data.clone(),
+    );
+
+    let since: u64 = (1 << 63) + (1 << 62) + TIME_INFO_UPDATE_INTERVAL;
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .since(since.pack())
+        .build();
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(500u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+    ];
+
+    let outputs_data = vec![build_next_time_info_cell_data(&input_data, time_index, now)];
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(ERROR_RELATIVE_SINCE).input_type_script(0)
+    );
+}
+
+#[test]
+fn test_success_batch_update() {
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    let type_script = context.
+        build_script(&out_point, out_point.as_bytes()).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    let now = Utc::now().timestamp() as u64;
+
+    // prepare cells, catch up two time info cells in one transaction
+    let since: u64 = 1 << 62;
+    let mut inputs = vec![];
+    let mut inputs_data = vec![];
+    let mut outputs = vec![];
+    for time_index in 0..2u8 {
+        let last_timestamp = now - TIME_INFO_UPDATE_INTERVAL * (time_index as u64 + 1);
+        let input_data = build_time_info_cell_data(time_index, last_timestamp, 0);
+        let input_out_point = context.create_cell(
+            CellOutput::new_builder()
+                .capacity(1000u64.pack())
+                .lock(lock_script.clone())
+                .type_(Some(type_script.clone()).pack())
+                .build(),
+            input_data.clone(),
+        );
+        inputs_data.push(input_data);
+        inputs.push(
+            CellInput::new_builder()
+                .previous_output(input_out_point)
+                .since((since + now).pack())
+                .build()
+        );
+        outputs.push(
+            CellOutput::new_builder()
+                .capacity(500u64.pack())
+                .lock(lock_script.clone())
+                .type_(Some(type_script.clone()).pack())
+                .build()
+        );
+    }
+
+    let outputs_data = vec![
+        build_next_time_info_cell_data(&inputs_data[0], 0, now),
+        build_next_time_info_cell_data(&inputs_data[1], 1, now),
+    ];
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .inputs(inputs)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_error_batch_update_with_unpaired_index() {
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    let type_script = context.
+        build_script(&out_point, out_point.as_bytes()).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    let now = Utc::now().timestamp() as u64;
+
+    // prepare cells, catch up two time info cells in one transaction
+    let since: u64 = 1 << 62;
+    let mut inputs = vec![];
+    let mut inputs_data = vec![];
+    let mut outputs = vec![];
+    for time_index in 0..2u8 {
+        let last_timestamp = now - TIME_INFO_UPDATE_INTERVAL * (time_index as u64 + 1);
+        let input_data = build_time_info_cell_data(time_index, last_timestamp, 0);
+        let input_out_point = context.create_cell(
+            CellOutput::new_builder()
+                .capacity(1000u64.pack())
+                .lock(lock_script.clone())
+                .type_(Some(type_script.clone()).pack())
+                .build(),
+            input_data.clone(),
+        );
+        inputs_data.push(input_data);
+        inputs.push(
+            CellInput::new_builder()
+                .previous_output(input_out_point)
+                .since((since + now).pack())
+                .build()
+        );
+        outputs.push(
+            CellOutput::new_builder()
+                .capacity(500u64.pack())
+                .lock(lock_script.clone())
+                .type_(Some(type_script.clone()).pack())
+                .build()
+        );
+    }
+
+    // inputs and outputs are paired by position, so the time index of each pair is different
+    let outputs_data = vec![
+        build_next_time_info_cell_data(&inputs_data[0], 1, now),
+        build_next_time_info_cell_data(&inputs_data[1], 0, now),
+    ];
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .inputs(inputs)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(ERROR_INVALID_TIME_INDEX).input_type_script(0)
+    );
+}
+
+#[test]
+fn test_success_update_with_time_index_state() {
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point.clone())
+        .build();
+
+    // time index state cell, always success type script stands for time index state type script
+    let state_type_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![1]))
+        .expect("script");
+    let state_type_hash = state_type_script.calc_script_hash();
+
+    let type_script = context.
+        build_script(&out_point, build_time_info_args(&out_point, vec![
+            (ARGS_FIELD_TIME_INDEX_STATE_TYPE_HASH, state_type_hash.as_bytes()),
+        ])).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    let now = Utc::now().timestamp() as u64;
+    let time_index = 0;
+
+    // prepare cells
+    let input_data = build_time_info_cell_data(time_index, now - TIME_INFO_UPDATE_INTERVAL, 0);
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        input_data.clone(),
+    );
+    let state_input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(state_type_script.clone()).pack())
+            .build(),
+        Bytes::from(vec![TIME_INFO_CELL_DATA_N - 1, TIME_INFO_CELL_DATA_N]),
+    );
+
+    let since: u64 = 1 << 62;
+    let inputs = vec![
+        CellInput::new_builder()
+            .previous_output(input_out_point)
+            .since((since + now).pack())
+            .build(),
+        CellInput::new_builder()
+            .previous_output(state_input_out_point)
+            .build(),
+    ];
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(500u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        CellOutput::new_builder()
+            .capacity(500u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(state_type_script.clone()).pack())
+            .build(),
+    ];
+
+    let outputs_data = vec![
+        build_next_time_info_cell_data(&input_data, time_index, now),
+        Bytes::from(vec![time_index, TIME_INFO_CELL_DATA_N]),
+    ];
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .inputs(inputs)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_error_update_with_stale_time_index_state() {
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point.clone())
+        .build();
+
+    // time index state cell, always success type script stands for time index state type script
+    let state_type_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![1]))
+        .expect("script");
+    let state_type_hash = state_type_script.calc_script_hash();
+
+    let type_script = context.
+        build_script(&out_point, build_time_info_args(&out_point, vec![
+            (ARGS_FIELD_TIME_INDEX_STATE_TYPE_HASH, state_type_hash.as_bytes()),
+        ])).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    let now = Utc::now().timestamp() as u64;
+    let time_index = 0;
+
+    // prepare cells
+    let input_data = build_time_info_cell_data(time_index, now - TIME_INFO_UPDATE_INTERVAL, 0);
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        input_data.clone(),
+    );
+    let state_input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(state_type_script.clone()).pack())
+            .build(),
+        Bytes::from(vec![TIME_INFO_CELL_DATA_N - 1, TIME_INFO_CELL_DATA_N]),
+    );
+
+    // time index state cell does not move to the time index of updated time info cell
+    let since: u64 = 1 << 62;
+    let inputs = vec![
+        CellInput::new_builder()
+            .previous_output(input_out_point)
+            .since((since + now).pack())
+            .build(),
+        CellInput::new_builder()
+            .previous_output(state_input_out_point)
+            .build(),
+    ];
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(500u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        CellOutput::new_builder()
+            .capacity(500u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(state_type_script.clone()).pack())
+            .build(),
+    ];
+
+    let outputs_data = vec![
+        build_next_time_info_cell_data(&input_data, time_index, now),
+        Bytes::from(vec![time_index + 1, TIME_INFO_CELL_DATA_N]),
+    ];
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .inputs(inputs)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(ERROR_INVALID_TIME_INDEX_STATE).input_type_script(0)
+    );
+}
+
+#[test]
+fn test_error_update_without_time_index_state_input() {
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point.clone())
+        .build();
+
+    // time index state cell, always success type script stands for time index state type script
+    let state_type_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![1]))
+        .expect("script");
+    let state_type_hash = state_type_script.calc_script_hash();
+
+    let type_script = context.
+        build_script(&out_point, build_time_info_args(&out_point, vec![
+            (ARGS_FIELD_TIME_INDEX_STATE_TYPE_HASH, state_type_hash.as_bytes()),
+        ])).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    let now = Utc::now().timestamp() as u64;
+    let time_index = 0;
+
+    // prepare cells
+    let input_data = build_time_info_cell_data(time_index, now - TIME_INFO_UPDATE_INTERVAL, 0);
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        input_data.clone(),
+    );
+    let _state_input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(state_type_script.clone()).pack())
+            .build(),
+        Bytes::from(vec![TIME_INFO_CELL_DATA_N - 1, TIME_INFO_CELL_DATA_N]),
+    );
+
+    // time index state cell is created instead of updated
+    let since: u64 = 1 << 62;
+    let inputs = vec![
+        CellInput::new_builder()
+            .previous_output(input_out_point)
+            .since((since + now).pack())
+            .build(),
+    ];
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(500u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        CellOutput::new_builder()
+            .capacity(500u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(state_type_script.clone()).pack())
+            .build(),
+    ];
+
+    let outputs_data = vec![
+        build_next_time_info_cell_data(&input_data, time_index, now),
+        Bytes::from(vec![time_index, TIME_INFO_CELL_DATA_N]),
+    ];
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .inputs(inputs)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(ERROR_INVALID_TIME_INDEX_STATE).input_type_script(0)
+    );
+}
+
+#[test]
+fn test_error_batch_update_with_time_index_state() {
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point.clone())
+        .build();
+
+    // time index state cell, always success type script stands for time index state type script
+    let state_type_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![1]))
+        .expect("script");
+    let state_type_hash = state_type_script.calc_script_hash();
+
+    let type_script = context.
+        build_script(&out_point, build_time_info_args(&out_point, vec![
+            (ARGS_FIELD_TIME_INDEX_STATE_TYPE_HASH, state_type_hash.as_bytes()),
+        ])).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    let now = Utc::now().timestamp() as u64;
+
+    // prepare cells, catch up two time info cells in one transaction
+    let since: u64 = 1 << 62;
+    let mut inputs = vec![];
+    let mut inputs_data = vec![];
+    let mut outputs = vec![];
+    for time_index in 0..2u8 {
+        let last_timestamp = now - TIME_INFO_UPDATE_INTERVAL * (time_index as u64 + 1);
+        let input_data = build_time_info_cell_data(time_index, last_timestamp, 0);
+        let input_out_point = context.create_cell(
+            CellOutput::new_builder()
+                .capacity(1000u64.pack())
+                .lock(lock_script.clone())
+                .type_(Some(type_script.clone()).pack())
+                .build(),
+            input_data.clone(),
+        );
+        inputs_data.push(input_data);
+        inputs.push(
+            CellInput::new_builder()
+                .previous_output(input_out_point)
+                .since((since + now).pack())
+                .build()
+        );
+        outputs.push(
+            CellOutput::new_builder()
+                .capacity(500u64.pack())
+                .lock(lock_script.clone())
+                .type_(Some(type_script.clone()).pack())
+                .build()
+        );
+    }
+    let state_input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(state_type_script.clone()).pack())
+            .build(),
+        Bytes::from(vec![TIME_INFO_CELL_DATA_N - 1, TIME_INFO_CELL_DATA_N]),
+    );
+    inputs.push(
+        CellInput::new_builder()
+            .previous_output(state_input_out_point)
+            .build()
+    );
+    outputs.push(
+        CellOutput::new_builder()
+            .capacity(500u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(state_type_script.clone()).pack())
+            .build()
+    );
+
+    // time index state cell can only move with one time info cell
+    let outputs_data = vec![
+        build_next_time_info_cell_data(&inputs_data[0], 0, now),
+        build_next_time_info_cell_data(&inputs_data[1], 1, now),
+        Bytes::from(vec![1, TIME_INFO_CELL_DATA_N]),
+    ];
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .inputs(inputs)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(ERROR_INVALID_TIME_INDEX_STATE).input_type_script(0)
+    );
+}
+
+#[test]
+fn test_success_update_within_fee_allowance() {
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    let type_script = context.
+        build_script(&out_point, build_time_info_args(&out_point, vec![
+            (ARGS_FIELD_FEE_ALLOWANCE, Bytes::from(100u64.to_be_bytes().to_vec())),
+        ])).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    let now = Utc::now().timestamp() as u64;
+    let time_index = 0;
+
+    // prepare cells
+    let input_data = build_time_info_cell_data(time_index, now - TIME_INFO_UPDATE_INTERVAL, 0);
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        input_data.clone(),
+    );
+
+    let since: u64 = 1 << 62;
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .since((since + now).pack())
+        .build();
+    // 100 shannons are paid as fee, within the fee allowance
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(900u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+    ];
+
+    let outputs_data = vec![build_next_time_info_cell_data(&input_data, time_index, now)];
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_error_update_with_changed_lock() {
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    let type_script = context.
+        build_script(&out_point, build_time_info_args(&out_point, vec![
+            (ARGS_FIELD_FEE_ALLOWANCE, Bytes::from(100u64.to_be_bytes().to_vec())),
+        ])).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let other_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![1]))
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    let now = Utc::now().timestamp() as u64;
+    let time_index = 0;
+
+    // prepare cells
+    let input_data = build_time_info_cell_data(time_index, now - TIME_INFO_UPDATE_INTERVAL, 0);
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        input_data.clone(),
+    );
+
+    let since: u64 = 1 << 62;
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .since((since + now).pack())
+        .build();
+    // lock of time info cell is changed
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(other_lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+    ];
+
+    let outputs_data = vec![build_next_time_info_cell_data(&input_data, time_index, now)];
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(ERROR_INVALID_CELL_LOCK).input_type_script(0)
+    );
+}
+
+#[test]
+fn test_error_update_exceed_fee_allowance() {
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    let type_script = context.
+        build_script(&out_point, build_time_info_args(&out_point, vec![
+            (ARGS_FIELD_FEE_ALLOWANCE, Bytes::from(100u64.to_be_bytes().to_vec())),
+        ])).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    let now = Utc::now().timestamp() as u64;
+    let time_index = 0;
+
+    // prepare cells
+    let input_data = build_time_info_cell_data(time_index, now - TIME_INFO_UPDATE_INTERVAL, 0);
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        input_data.clone(),
+    );
+
+    let since: u64 = 1 << 62;
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .since((since + now).pack())
+        .build();
+    // 500 shannons are drained, exceeding the fee allowance
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(500u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+    ];
+
+    let outputs_data = vec![build_next_time_info_cell_data(&input_data, time_index, now)];
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(ERROR_INVALID_CELL_CAPACITY).input_type_script(0)
+    );
+}
+
+#[test]
+fn test_error_update_without_oracle_signature() {
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    // deploy secp256k1 library
+    let secp256k1_lib_bin = load_secp256k1_lib();
+    let secp256k1_lib_code_hash = CellOutput::calc_data_hash(&secp256k1_lib_bin);
+    let secp256k1_lib_out_point = context.deploy_cell(secp256k1_lib_bin);
+    let secp256k1_lib_dep = CellDep::new_builder()
+        .out_point(secp256k1_lib_out_point)
+        .build();
+    let type_script = context.
+        build_script(&out_point, build_time_info_args(&out_point, vec![
+            (ARGS_FIELD_ORACLE_KEY, build_oracle_key(ORACLE_KEY_TYPE_SECP256K1)),
+            (ARGS_FIELD_SECP256K1_LIB_CODE_HASH, secp256k1_lib_code_hash.as_bytes()),
+        ])).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    let now = Utc::now().timestamp() as u64;
+    let time_index = 0;
+
+    // prepare cells
+    let input_data = build_time_info_cell_data(time_index, now - TIME_INFO_UPDATE_INTERVAL, 0);
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        input_data.clone(),
+    );
+
+    let since: u64 = 1 << 62;
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .since((since + now).pack())
+        .build();
+    // output type of witness carries no oracle signature
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+    ];
+
+    let outputs_data = vec![build_next_time_info_cell_data(&input_data, time_index, now)];
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .cell_dep(secp256k1_lib_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(ERROR_INVALID_ORACLE_WITNESS).input_type_script(0)
+    );
+}
+
+#[test]
+fn test_error_update_with_unsupported_oracle_key_type() {
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    let type_script = context.
+        build_script(&out_point, build_time_info_args(&out_point, vec![
+            (ARGS_FIELD_ORACLE_KEY, build_oracle_key(ORACLE_KEY_TYPE_ED25519 + 1)),
+            (ARGS_FIELD_SECP256K1_LIB_CODE_HASH, Bytes::from([1u8; 32].to_vec())),
+        ])).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    let now = Utc::now().timestamp() as u64;
+    let time_index = 0;
+
+    // prepare cells
+    let input_data = build_time_info_cell_data(time_index, now - TIME_INFO_UPDATE_INTERVAL, 0);
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        input_data.clone(),
+    );
+
+    let since: u64 = 1 << 62;
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .since((since + now).pack())
+        .build();
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+    ];
+
+    let outputs_data = vec![build_next_time_info_cell_data(&input_data, time_index, now)];
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(ERROR_INVALID_ARGUMENT).input_type_script(0)
+    );
+}
+
+#[test]
+fn test_success_update_with_oracle_signature() {
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    // deploy secp256k1 library
+    let secp256k1_lib_bin = load_secp256k1_lib();
+    let secp256k1_lib_code_hash = CellOutput::calc_data_hash(&secp256k1_lib_bin);
+    let secp256k1_lib_out_point = context.deploy_cell(secp256k1_lib_bin);
+    let secp256k1_lib_dep = CellDep::new_builder()
+        .out_point(secp256k1_lib_out_point)
+        .build();
+    let oracle_privkey = Generator::random_privkey();
+    let type_script = context.
+        build_script(&out_point, build_time_info_args(&out_point, vec![
+            (ARGS_FIELD_ORACLE_KEY, build_secp256k1_oracle_key(&oracle_privkey)),
+            (ARGS_FIELD_SECP256K1_LIB_CODE_HASH, secp256k1_lib_code_hash.as_bytes()),
+        ])).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    let now = Utc::now().timestamp() as u64;
+    let time_index = 0;
+
+    // prepare cells
+    let input_data = build_time_info_cell_data(time_index, now - TIME_INFO_UPDATE_INTERVAL, 0);
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        input_data.clone(),
+    );
+
+    let since: u64 = 1 << 62;
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point.clone())
+        .since((since + now).pack())
+        .build();
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+    ];
+
+    let outputs_data = vec![build_next_time_info_cell_data(&input_data, time_index, now)];
+    // oracle signs the new timestamp in output type of witness
+    let witness = WitnessArgs::new_builder()
+        .output_type(Some(sign_oracle_timestamp(&oracle_privkey, time_index, now, &input_out_point)).pack())
+        .build();
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .witness(witness.as_bytes().pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .cell_dep(secp256k1_lib_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_error_update_with_wrong_oracle_key() {
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    // deploy secp256k1 library
+    let secp256k1_lib_bin = load_secp256k1_lib();
+    let secp256k1_lib_code_hash = CellOutput::calc_data_hash(&secp256k1_lib_bin);
+    let secp256k1_lib_out_point = context.deploy_cell(secp256k1_lib_bin);
+    let secp256k1_lib_dep = CellDep::new_builder()
+        .out_point(secp256k1_lib_out_point)
+        .build();
+    let oracle_privkey = Generator::random_privkey();
+    let other_privkey = Generator::random_privkey();
+    let type_script = context.
+        build_script(&out_point, build_time_info_args(&out_point, vec![
+            (ARGS_FIELD_ORACLE_KEY, build_secp256k1_oracle_key(&oracle_privkey)),
+            (ARGS_FIELD_SECP256K1_LIB_CODE_HASH, secp256k1_lib_code_hash.as_bytes()),
+        ])).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    let now = Utc::now().timestamp() as u64;
+    let time_index = 0;
+
+    // prepare cells
+    let input_data = build_time_info_cell_data(time_index, now - TIME_INFO_UPDATE_INTERVAL, 0);
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        input_data.clone(),
+    );
+
+    let since: u64 = 1 << 62;
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point.clone())
+        .since((since + now).pack())
+        .build();
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+    ];
+
+    let outputs_data = vec![build_next_time_info_cell_data(&input_data, time_index, now)];
+    // the new timestamp is signed by a key which is not the oracle key in args
+    let witness = WitnessArgs::new_builder()
+        .output_type(Some(sign_oracle_timestamp(&other_privkey, time_index, now, &input_out_point)).pack())
+        .build();
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .witness(witness.as_bytes().pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .cell_dep(secp256k1_lib_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(ERROR_INVALID_ORACLE_SIGNATURE).input_type_script(0)
+    );
+}
+
+#[test]
+fn test_success_update_with_ed25519_oracle_signature() {
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    let oracle_key_pair = random_ed25519_key_pair();
+    let type_script = context.
+        build_script(&out_point, build_time_info_args(&out_point, vec![
+            (ARGS_FIELD_ORACLE_KEY, build_ed25519_oracle_key(&oracle_key_pair)),
+        ])).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    let now = Utc::now().timestamp() as u64;
+    let time_index = 0;
+
+    // prepare cells
+    let input_data = build_time_info_cell_data(time_index, now - TIME_INFO_UPDATE_INTERVAL, 0);
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        input_data.clone(),
+    );
+
+    let since: u64 = 1 << 62;
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point.clone())
+        .since((since + now).pack())
+        .build();
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+    ];
+
+    let outputs_data = vec![build_next_time_info_cell_data(&input_data, time_index, now)];
+    // ed25519 oracle signs the new timestamp without secp256k1 library
+    let witness = WitnessArgs::new_builder()
+        .output_type(Some(sign_ed25519_oracle_timestamp(&oracle_key_pair, time_index, now, &input_out_point)).pack())
+        .build();
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .witness(witness.as_bytes().pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_error_update_with_wrong_ed25519_oracle_key() {
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    let oracle_key_pair = random_ed25519_key_pair();
+    let other_key_pair = random_ed25519_key_pair();
+    let type_script = context.
+        build_script(&out_point, build_time_info_args(&out_point, vec![
+            (ARGS_FIELD_ORACLE_KEY, build_ed25519_oracle_key(&oracle_key_pair)),
+        ])).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    let now = Utc::now().timestamp() as u64;
+    let time_index = 0;
+
+    // prepare cells
+    let input_data = build_time_info_cell_data(time_index, now - TIME_INFO_UPDATE_INTERVAL, 0);
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        input_data.clone(),
+    );
+
+    let since: u64 = 1 << 62;
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point.clone())
+        .since((since + now).pack())
+        .build();
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+    ];
+
+    let outputs_data = vec![build_next_time_info_cell_data(&input_data, time_index, now)];
+    // the new timestamp is signed by an ed25519 key which is not the oracle key in args
+    let witness = WitnessArgs::new_builder()
+        .output_type(Some(sign_ed25519_oracle_timestamp(&other_key_pair, time_index, now, &input_out_point)).pack())
+        .build();
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .witness(witness.as_bytes().pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(ERROR_INVALID_ORACLE_SIGNATURE).input_type_script(0)
+    );
+}
+
+#[test]
+fn test_error_update_with_tampered_ed25519_oracle_signature() {
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    let oracle_key_pair = random_ed25519_key_pair();
+    let type_script = context.
+        build_script(&out_point, build_time_info_args(&out_point, vec![
+            (ARGS_FIELD_ORACLE_KEY, build_ed25519_oracle_key(&oracle_key_pair)),
+        ])).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    let now = Utc::now().timestamp() as u64;
+    let time_index = 0;
+
+    // prepare cells
+    let input_data = build_time_info_cell_data(time_index, now - TIME_INFO_UPDATE_INTERVAL, 0);
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        input_data.clone(),
+    );
+
+    let since: u64 = 1 << 62;
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point.clone())
+        .since((since + now).pack())
+        .build();
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+    ];
+
+    let outputs_data = vec![build_next_time_info_cell_data(&input_data, time_index, now)];
+    // the signature of oracle is over another timestamp
+    let witness = WitnessArgs::new_builder()
+        .output_type(Some(sign_ed25519_oracle_timestamp(&oracle_key_pair, time_index, now - 1, &input_out_point)).pack())
+        .build();
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .witness(witness.as_bytes().pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(ERROR_INVALID_ORACLE_SIGNATURE).input_type_script(0)
+    );
+}
+
+#[test]
+fn test_error_update_with_replayed_oracle_signature() {
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    // deploy secp256k1 library
+    let secp256k1_lib_bin = load_secp256k1_lib();
+    let secp256k1_lib_code_hash = CellOutput::calc_data_hash(&secp256k1_lib_bin);
+    let secp256k1_lib_out_point = context.deploy_cell(secp256k1_lib_bin);
+    let secp256k1_lib_dep = CellDep::new_builder()
+        .out_point(secp256k1_lib_out_point)
+        .build();
+    let oracle_privkey = Generator::random_privkey();
+    let type_script = context.
+        build_script(&out_point, build_time_info_args(&out_point, vec![
+            (ARGS_FIELD_ORACLE_KEY, build_secp256k1_oracle_key(&oracle_privkey)),
+            (ARGS_FIELD_SECP256K1_LIB_CODE_HASH, secp256k1_lib_code_hash.as_bytes()),
+        ])).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    let now = Utc::now().timestamp() as u64;
+    let time_index = 0;
+
+    // prepare cells
+    let input_data = build_time_info_cell_data(time_index, now - TIME_INFO_UPDATE_INTERVAL, 0);
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        input_data.clone(),
+    );
+    // the same timestamp is signed for the update of another cell
+    let other_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+
+    let since: u64 = 1 << 62;
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point.clone())
+        .since((since + now).pack())
+        .build();
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+    ];
+
+    let outputs_data = vec![build_next_time_info_cell_data(&input_data, time_index, now)];
+    // signature over another previous out point cannot be replayed on this update
+    let witness = WitnessArgs::new_builder()
+        .output_type(Some(sign_oracle_timestamp(&oracle_privkey, time_index, now, &other_out_point)).pack())
+        .build();
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .witness(witness.as_bytes().pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .cell_dep(secp256k1_lib_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(ERROR_INVALID_ORACLE_SIGNATURE).input_type_script(0)
+    );
+}
+
+#[test]
+fn test_error_update_without_secp256k1_lib() {
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    // secp256k1 library is not in cell deps
+    let secp256k1_lib_code_hash = CellOutput::calc_data_hash(&load_secp256k1_lib());
+    let oracle_privkey = Generator::random_privkey();
+    let type_script = context.
+        build_script(&out_point, build_time_info_args(&out_point, vec![
+            (ARGS_FIELD_ORACLE_KEY, build_secp256k1_oracle_key(&oracle_privkey)),
+            (ARGS_FIELD_SECP256K1_LIB_CODE_HASH, secp256k1_lib_code_hash.as_bytes()),
+        ])).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    let now = Utc::now().timestamp() as u64;
+    let time_index = 0;
+
+    // prepare cells
+    let input_data = build_time_info_cell_data(time_index, now - TIME_INFO_UPDATE_INTERVAL, 0);
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        input_data.clone(),
+    );
+
+    let since: u64 = 1 << 62;
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point.clone())
+        .since((since + now).pack())
+        .build();
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+    ];
+
+    let outputs_data = vec![build_next_time_info_cell_data(&input_data, time_index, now)];
+    // oracle signs the new timestamp in output type of witness
+    let witness = WitnessArgs::new_builder()
+        .output_type(Some(sign_oracle_timestamp(&oracle_privkey, time_index, now, &input_out_point)).pack())
+        .build();
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .witness(witness.as_bytes().pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(ERROR_INVALID_ORACLE_LIB).input_type_script(0)
+    );
+}
+
+#[test]
+fn test_error_update_with_mismatched_secp256k1_lib() {
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    // cell dep of the library code hash in args does not export the secp256k1 functions
+    let secp256k1_lib_code_hash = CellOutput::calc_data_hash(&ALWAYS_SUCCESS);
+    let oracle_privkey = Generator::random_privkey();
+    let type_script = context.
+        build_script(&out_point, build_time_info_args(&out_point, vec![
+            (ARGS_FIELD_ORACLE_KEY, build_secp256k1_oracle_key(&oracle_privkey)),
+            (ARGS_FIELD_SECP256K1_LIB_CODE_HASH, secp256k1_lib_code_hash.as_bytes()),
+        ])).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    let now = Utc::now().timestamp() as u64;
+    let time_index = 0;
+
+    // prepare cells
+    let input_data = build_time_info_cell_data(time_index, now - TIME_INFO_UPDATE_INTERVAL, 0);
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        input_data.clone(),
+    );
+
+    let since: u64 = 1 << 62;
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point.clone())
+        .since((since + now).pack())
+        .build();
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+    ];
+
+    let outputs_data = vec![build_next_time_info_cell_data(&input_data, time_index, now)];
+    // oracle signs the new timestamp in output type of witness
+    let witness = WitnessArgs::new_builder()
+        .output_type(Some(sign_oracle_timestamp(&oracle_privkey, time_index, now, &input_out_point)).pack())
+        .build();
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .witness(witness.as_bytes().pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(ERROR_INVALID_ORACLE_LIB).input_type_script(0)
+    );
+}
+
+#[test]
+fn test_error_update_without_oracle_quorum_attestations() {
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    // deploy secp256k1 library
+    let secp256k1_lib_bin = load_secp256k1_lib();
+    let secp256k1_lib_code_hash = CellOutput::calc_data_hash(&secp256k1_lib_bin);
+    let secp256k1_lib_out_point = context.deploy_cell(secp256k1_lib_bin);
+    let secp256k1_lib_dep = CellDep::new_builder()
+        .out_point(secp256k1_lib_out_point)
+        .build();
+    let type_script = context.
+        build_script(&out_point, build_time_info_args(&out_point, vec![
+            (ARGS_FIELD_ORACLE_QUORUM, build_oracle_quorum(2, 3)),
+            (ARGS_FIELD_SECP256K1_LIB_CODE_HASH, secp256k1_lib_code_hash.as_bytes()),
+        ])).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    let now = Utc::now().timestamp() as u64;
+    let time_index = 0;
+
+    // prepare cells
+    let input_data = build_time_info_cell_data(time_index, now - TIME_INFO_UPDATE_INTERVAL, 0);
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        input_data.clone(),
+    );
+
+    let since: u64 = 1 << 62;
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .since((since + now).pack())
+        .build();
+    // output type of witness carries no oracle attestation
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+    ];
+
+    let outputs_data = vec![build_next_time_info_cell_data(&input_data, time_index, now)];
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .cell_dep(secp256k1_lib_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(ERROR_INVALID_ORACLE_WITNESS).input_type_script(0)
+    );
+}
+
+#[test]
+fn test_success_update_with_oracle_quorum_median() {
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    // deploy secp256k1 library
+    let secp256k1_lib_bin = load_secp256k1_lib();
+    let secp256k1_lib_code_hash = CellOutput::calc_data_hash(&secp256k1_lib_bin);
+    let secp256k1_lib_out_point = context.deploy_cell(secp256k1_lib_bin);
+    let secp256k1_lib_dep = CellDep::new_builder()
+        .out_point(secp256k1_lib_out_point)
+        .build();
+    let oracle_privkeys: Vec<Privkey> = (0..3).map(|_| Generator::random_privkey()).collect();
+    let type_script = context.
+        build_script(&out_point, build_time_info_args(&out_point, vec![
+            (ARGS_FIELD_ORACLE_QUORUM, build_secp256k1_oracle_quorum(2, &oracle_privkeys)),
+            (ARGS_FIELD_SECP256K1_LIB_CODE_HASH, secp256k1_lib_code_hash.as_bytes()),
+        ])).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    let now = Utc::now().timestamp() as u64;
+    let time_index = 0;
+
+    // prepare cells
+    let input_data = build_time_info_cell_data(time_index, now - TIME_INFO_UPDATE_INTERVAL, 0);
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        input_data.clone(),
+    );
+
+    let since: u64 = 1 << 62;
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point.clone())
+        .since((since + now).pack())
+        .build();
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+    ];
+
+    let outputs_data = vec![build_next_time_info_cell_data(&input_data, time_index, now)];
+    // the new timestamp is the median of attested timestamps
+    let attestations = build_oracle_attestations(&oracle_privkeys, time_index, &input_out_point, vec![
+        (0, now - 2),
+        (1, now),
+        (2, now + 1),
+    ]);
+    // oracle attestations are in output type of witness
+    let witness = WitnessArgs::new_builder()
+        .output_type(Some(attestations).pack())
+        .build();
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .witness(witness.as_bytes().pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .cell_dep(secp256k1_lib_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_error_update_below_oracle_quorum_threshold() {
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    // deploy secp256k1 library
+    let secp256k1_lib_bin = load_secp256k1_lib();
+    let secp256k1_lib_code_hash = CellOutput::calc_data_hash(&secp256k1_lib_bin);
+    let secp256k1_lib_out_point = context.deploy_cell(secp256k1_lib_bin);
+    let secp256k1_lib_dep = CellDep::new_builder()
+        .out_point(secp256k1_lib_out_point)
+        .build();
+    let oracle_privkeys: Vec<Privkey> = (0..3).map(|_| Generator::random_privkey()).collect();
+    let type_script = context.
+        build_script(&out_point, build_time_info_args(&out_point, vec![
+            (ARGS_FIELD_ORACLE_QUORUM, build_secp256k1_oracle_quorum(2, &oracle_privkeys)),
+            (ARGS_FIELD_SECP256K1_LIB_CODE_HASH, secp256k1_lib_code_hash.as_bytes()),
+        ])).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    let now = Utc::now().timestamp() as u64;
+    let time_index = 0;
+
+    // prepare cells
+    let input_data = build_time_info_cell_data(time_index, now - TIME_INFO_UPDATE_INTERVAL, 0);
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        input_data.clone(),
+    );
+
+    let since: u64 = 1 << 62;
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point.clone())
+        .since((since + now).pack())
+        .build();
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+    ];
+
+    let outputs_data = vec![build_next_time_info_cell_data(&input_data, time_index, now)];
+    // only one oracle attests while the threshold is two
+    let attestations = build_oracle_attestations(&oracle_privkeys, time_index, &input_out_point, vec![
+        (1, now),
+    ]);
+    // oracle attestations are in output type of witness
+    let witness = WitnessArgs::new_builder()
+        .output_type(Some(attestations).pack())
+        .build();
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .witness(witness.as_bytes().pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .cell_dep(secp256k1_lib_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(ERROR_INVALID_ORACLE_QUORUM).input_type_script(0)
+    );
+}
+
+#[test]
+fn test_error_update_off_oracle_quorum_median() {
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    // deploy secp256k1 library
+    let secp256k1_lib_bin = load_secp256k1_lib();
+    let secp256k1_lib_code_hash = CellOutput::calc_data_hash(&secp256k1_lib_bin);
+    let secp256k1_lib_out_point = context.deploy_cell(secp256k1_lib_bin);
+    let secp256k1_lib_dep = CellDep::new_builder()
+        .out_point(secp256k1_lib_out_point)
+        .build();
+    let oracle_privkeys: Vec<Privkey> = (0..3).map(|_| Generator::random_privkey()).collect();
+    let type_script = context.
+        build_script(&out_point, build_time_info_args(&out_point, vec![
+            (ARGS_FIELD_ORACLE_QUORUM, build_secp256k1_oracle_quorum(2, &oracle_privkeys)),
+            (ARGS_FIELD_SECP256K1_LIB_CODE_HASH, secp256k1_lib_code_hash.as_bytes()),
+        ])).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    let now = Utc::now().timestamp() as u64;
+    let time_index = 0;
+
+    // prepare cells
+    let input_data = build_time_info_cell_data(time_index, now - TIME_INFO_UPDATE_INTERVAL, 0);
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        input_data.clone(),
+    );
+
+    let since: u64 = 1 << 62;
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point.clone())
+        .since((since + now).pack())
+        .build();
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+    ];
+
+    let outputs_data = vec![build_next_time_info_cell_data(&input_data, time_index, now)];
+    // the new timestamp is not the median of attested timestamps
+    let attestations = build_oracle_attestations(&oracle_privkeys, time_index, &input_out_point, vec![
+        (0, now - 2),
+        (1, now - 1),
+        (2, now + 1),
+    ]);
+    // oracle attestations are in output type of witness
+    let witness = WitnessArgs::new_builder()
+        .output_type(Some(attestations).pack())
+        .build();
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .witness(witness.as_bytes().pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .cell_dep(secp256k1_lib_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(ERROR_INVALID_ORACLE_TIMESTAMP).input_type_script(0)
+    );
+}
+
+#[test]
+fn test_success_update_within_oracle_spread() {
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    // deploy secp256k1 library
+    let secp256k1_lib_bin = load_secp256k1_lib();
+    let secp256k1_lib_code_hash = CellOutput::calc_data_hash(&secp256k1_lib_bin);
+    let secp256k1_lib_out_point = context.deploy_cell(secp256k1_lib_bin);
+    let secp256k1_lib_dep = CellDep::new_builder()
+        .out_point(secp256k1_lib_out_point)
+        .build();
+    let oracle_privkeys: Vec<Privkey> = (0..3).map(|_| Generator::random_privkey()).collect();
+    let type_script = context.
+        build_script(&out_point, build_time_info_args(&out_point, vec![
+            (ARGS_FIELD_ORACLE_QUORUM, build_secp256k1_oracle_quorum(2, &oracle_privkeys)),
+            (ARGS_FIELD_SECP256K1_LIB_CODE_HASH, secp256k1_lib_code_hash.as_bytes()),
+            (ARGS_FIELD_ORACLE_SPREAD, Bytes::from(5u32.to_be_bytes().to_vec())),
+        ])).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    let now = Utc::now().timestamp() as u64;
+    let time_index = 0;
+
+    // prepare cells
+    let input_data = build_time_info_cell_data(time_index, now - TIME_INFO_UPDATE_INTERVAL, 0);
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        input_data.clone(),
+    );
+
+    let since: u64 = 1 << 62;
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point.clone())
+        .since((since + now).pack())
+        .build();
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+    ];
+
+    let outputs_data = vec![build_next_time_info_cell_data(&input_data, time_index, now)];
+    // every attested timestamp is within the spread of the new timestamp
+    let attestations = build_oracle_attestations(&oracle_privkeys, time_index, &input_out_point, vec![
+        (0, now - 3),
+        (2, now + 2),
+    ]);
+    // oracle attestations are in output type of witness
+    let witness = WitnessArgs::new_builder()
+        .output_type(Some(attestations).pack())
+        .build();
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .witness(witness.as_bytes().pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .cell_dep(secp256k1_lib_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_error_update_exceed_oracle_spread() {
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    // deploy secp256k1 library
+    let secp256k1_lib_bin = load_secp256k1_lib();
+    let secp256k1_lib_code_hash = CellOutput::calc_data_hash(&secp256k1_lib_bin);
+    let secp256k1_lib_out_point = context.deploy_cell(secp256k1_lib_bin);
+    let secp256k1_lib_dep = CellDep::new_builder()
+        .out_point(secp256k1_lib_out_point)
+        .build();
+    let oracle_privkeys: Vec<Privkey> = (0..3).map(|_| Generator::random_privkey()).collect();
+    let type_script = context.
+        build_script(&out_point, build_time_info_args(&out_point, vec![
+            (ARGS_FIELD_ORACLE_QUORUM, build_secp256k1_oracle_quorum(2, &oracle_privkeys)),
+            (ARGS_FIELD_SECP256K1_LIB_CODE_HASH, secp256k1_lib_code_hash.as_bytes()),
+            (ARGS_FIELD_ORACLE_SPREAD, Bytes::from(5u32.to_be_bytes().to_vec())),
+        ])).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    let now = Utc::now().timestamp() as u64;
+    let time_index = 0;
+
+    // prepare cells
+    let input_data = build_time_info_cell_data(time_index, now - TIME_INFO_UPDATE_INTERVAL, 0);
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        input_data.clone(),
+    );
+
+    let since: u64 = 1 << 62;
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point.clone())
+        .since((since + now).pack())
+        .build();
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+    ];
+
+    let outputs_data = vec![build_next_time_info_cell_data(&input_data, time_index, now)];
+    // one attested timestamp is out of the spread of the new timestamp
+    let attestations = build_oracle_attestations(&oracle_privkeys, time_index, &input_out_point, vec![
+        (0, now - 10),
+        (1, now),
+    ]);
+    // oracle attestations are in output type of witness
+    let witness = WitnessArgs::new_builder()
+        .output_type(Some(attestations).pack())
+        .build();
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .witness(witness.as_bytes().pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .cell_dep(secp256k1_lib_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(ERROR_INVALID_ORACLE_TIMESTAMP).input_type_script(0)
+    );
+}
+
+#[test]
+fn test_error_update_with_out_of_range_oracle_index() {
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    // deploy secp256k1 library
+    let secp256k1_lib_bin = load_secp256k1_lib();
+    let secp256k1_lib_code_hash = CellOutput::calc_data_hash(&secp256k1_lib_bin);
+    let secp256k1_lib_out_point = context.deploy_cell(secp256k1_lib_bin);
+    let secp256k1_lib_dep = CellDep::new_builder()
+        .out_point(secp256k1_lib_out_point)
+        .build();
+    let oracle_privkeys: Vec<Privkey> = (0..3).map(|_| Generator::random_privkey()).collect();
+    let type_script = context.
+        build_script(&out_point, build_time_info_args(&out_point, vec![
+            (ARGS_FIELD_ORACLE_QUORUM, build_secp256k1_oracle_quorum(1, &oracle_privkeys[..2])),
+            (ARGS_FIELD_SECP256K1_LIB_CODE_HASH, secp256k1_lib_code_hash.as_bytes()),
+        ])).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    let now = Utc::now().timestamp() as u64;
+    let time_index = 0;
+
+    // prepare cells
+    let input_data = build_time_info_cell_data(time_index, now - TIME_INFO_UPDATE_INTERVAL, 0);
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        input_data.clone(),
+    );
+
+    let since: u64 = 1 << 62;
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point.clone())
+        .since((since + now).pack())
+        .build();
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+    ];
+
+    let outputs_data = vec![build_next_time_info_cell_data(&input_data, time_index, now)];
+    // oracle index 2 is out of the quorum of two oracles
+    let attestations = build_oracle_attestations(&oracle_privkeys, time_index, &input_out_point, vec![
+        (2, now),
+    ]);
+    // oracle attestations are in output type of witness
+    let witness = WitnessArgs::new_builder()
+        .output_type(Some(attestations).pack())
+        .build();
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .witness(witness.as_bytes().pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .cell_dep(secp256k1_lib_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(ERROR_INVALID_ORACLE_WITNESS).input_type_script(0)
+    );
+}
+
+#[test]
+fn test_error_update_oracle_entries_without_quorum() {
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    let type_script = context.
+        build_script(&out_point, build_time_info_args(&out_point, vec![])).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    let now = Utc::now().timestamp() as u64;
+    let time_index = 0;
+
+    // prepare cells
+    let input_data = build_time_info_cell_data(time_index, now - TIME_INFO_UPDATE_INTERVAL, 0);
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        input_data.clone(),
+    );
+
+    let since: u64 = 1 << 62;
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .since((since + now).pack())
+        .build();
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+    ];
+
+    // oracle entries are not attested by any oracle quorum
+    let outputs_data = vec![build_time_info_cell_data_with_oracle_entries(
+        build_next_time_info_cell_data(&input_data, time_index, now),
+        vec![(0, now)],
+    )];
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(ERROR_INVALID_ORACLE_ENTRIES).input_type_script(0)
+    );
+}
+
+#[test]
+fn test_success_update_with_attested_oracle_entries() {
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    // deploy secp256k1 library
+    let secp256k1_lib_bin = load_secp256k1_lib();
+    let secp256k1_lib_code_hash = CellOutput::calc_data_hash(&secp256k1_lib_bin);
+    let secp256k1_lib_out_point = context.deploy_cell(secp256k1_lib_bin);
+    let secp256k1_lib_dep = CellDep::new_builder()
+        .out_point(secp256k1_lib_out_point)
+        .build();
+    let oracle_privkeys: Vec<Privkey> = (0..3).map(|_| Generator::random_privkey()).collect();
+    let type_script = context.
+        build_script(&out_point, build_time_info_args(&out_point, vec![
+            (ARGS_FIELD_ORACLE_QUORUM, build_secp256k1_oracle_quorum(2, &oracle_privkeys)),
+            (ARGS_FIELD_SECP256K1_LIB_CODE_HASH, secp256k1_lib_code_hash.as_bytes()),
+        ])).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    let now = Utc::now().timestamp() as u64;
+    let time_index = 0;
+
+    // prepare cells
+    let input_data = build_time_info_cell_data(time_index, now - TIME_INFO_UPDATE_INTERVAL, 0);
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        input_data.clone(),
+    );
+
+    let since: u64 = 1 << 62;
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point.clone())
+        .since((since + now).pack())
+        .build();
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+    ];
+
+    // oracle entries are exactly the attested timestamps
+    let outputs_data = vec![build_time_info_cell_data_with_oracle_entries(
+        build_next_time_info_cell_data(&input_data, time_index, now),
+        vec![
+            (0, now - 2),
+            (1, now),
+            (2, now + 1),
+        ],
+    )];
+    let attestations = build_oracle_attestations(&oracle_privkeys, time_index, &input_out_point, vec![
+        (0, now - 2),
+        (1, now),
+        (2, now + 1),
+    ]);
+    // oracle attestations are in output type of witness
+    let witness = WitnessArgs::new_builder()
+        .output_type(Some(attestations).pack())
+        .build();
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .witness(witness.as_bytes().pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .cell_dep(secp256k1_lib_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_error_update_with_unattested_oracle_entries() {
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    // deploy secp256k1 library
+    let secp256k1_lib_bin = load_secp256k1_lib();
+    let secp256k1_lib_code_hash = CellOutput::calc_data_hash(&secp256k1_lib_bin);
+    let secp256k1_lib_out_point = context.deploy_cell(secp256k1_lib_bin);
+    let secp256k1_lib_dep = CellDep::new_builder()
+        .out_point(secp256k1_lib_out_point)
+        .build();
+    let oracle_privkeys: Vec<Privkey> = (0..3).map(|_| Generator::random_privkey()).collect();
+    let type_script = context.
+        build_script(&out_point, build_time_info_args(&out_point, vec![
+            (ARGS_FIELD_ORACLE_QUORUM, build_secp256k1_oracle_quorum(2, &oracle_privkeys)),
+            (ARGS_FIELD_SECP256K1_LIB_CODE_HASH, secp256k1_lib_code_hash.as_bytes()),
+        ])).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    let now = Utc::now().timestamp() as u64;
+    let time_index = 0;
+
+    // prepare cells
+    let input_data = build_time_info_cell_data(time_index, now - TIME_INFO_UPDATE_INTERVAL, 0);
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        input_data.clone(),
+    );
+
+    let since: u64 = 1 << 62;
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point.clone())
+        .since((since + now).pack())
+        .build();
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+    ];
+
+    // oracle entries differ from the attested timestamps
+    let outputs_data = vec![build_time_info_cell_data_with_oracle_entries(
+        build_next_time_info_cell_data(&input_data, time_index, now),
+        vec![
+            (0, now - 2),
+            (1, now),
+            (2, now + 2),
+        ],
+    )];
+    let attestations = build_oracle_attestations(&oracle_privkeys, time_index, &input_out_point, vec![
+        (0, now - 2),
+        (1, now),
+        (2, now + 1),
+    ]);
+    // oracle attestations are in output type of witness
+    let witness = WitnessArgs::new_builder()
+        .output_type(Some(attestations).pack())
+        .build();
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .witness(witness.as_bytes().pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .cell_dep(secp256k1_lib_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(ERROR_INVALID_ORACLE_ENTRIES).input_type_script(0)
+    );
+}
+
+#[test]
+fn test_error_update_with_oracle_entries_off_median() {
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    // deploy secp256k1 library
+    let secp256k1_lib_bin = load_secp256k1_lib();
+    let secp256k1_lib_code_hash = CellOutput::calc_data_hash(&secp256k1_lib_bin);
+    let secp256k1_lib_out_point = context.deploy_cell(secp256k1_lib_bin);
+    let secp256k1_lib_dep = CellDep::new_builder()
+        .out_point(secp256k1_lib_out_point)
+        .build();
+    let oracle_privkeys: Vec<Privkey> = (0..3).map(|_| Generator::random_privkey()).collect();
+    let type_script = context.
+        build_script(&out_point, build_time_info_args(&out_point, vec![
+            (ARGS_FIELD_ORACLE_QUORUM, build_secp256k1_oracle_quorum(2, &oracle_privkeys)),
+            (ARGS_FIELD_SECP256K1_LIB_CODE_HASH, secp256k1_lib_code_hash.as_bytes()),
+            (ARGS_FIELD_ORACLE_SPREAD, Bytes::from(5u32.to_be_bytes().to_vec())),
+        ])).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    let now = Utc::now().timestamp() as u64;
+    let time_index = 0;
+
+    // prepare cells
+    let input_data = build_time_info_cell_data(time_index, now - TIME_INFO_UPDATE_INTERVAL, 0);
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        input_data.clone(),
+    );
+
+    let since: u64 = 1 << 62;
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point.clone())
+        .since((since + now).pack())
+        .build();
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+    ];
+
+    // the new timestamp is within the spread, but it is not the median of oracle entries
+    let outputs_data = vec![build_time_info_cell_data_with_oracle_entries(
+        build_next_time_info_cell_data(&input_data, time_index, now),
+        vec![
+            (0, now - 1),
+            (1, now + 1),
+            (2, now + 2),
+        ],
+    )];
+    let attestations = build_oracle_attestations(&oracle_privkeys, time_index, &input_out_point, vec![
+        (0, now - 1),
+        (1, now + 1),
+        (2, now + 2),
+    ]);
+    // oracle attestations are in output type of witness
+    let witness = WitnessArgs::new_builder()
+        .output_type(Some(attestations).pack())
+        .build();
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .witness(witness.as_bytes().pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .cell_dep(secp256k1_lib_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(ERROR_INVALID_ORACLE_ENTRIES).input_type_script(0)
+    );
+}
+
+#[test]
+fn test_error_update_without_sequence_increment() {
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    let type_script = context.
+        build_script(&out_point, build_time_info_args(&out_point, vec![])).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    let now = Utc::now().timestamp() as u64;
+    let time_index = 0;
+
+    // prepare cells
+    let input_data = build_time_info_cell_data(time_index, now - TIME_INFO_UPDATE_INTERVAL, 0);
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        input_data.clone(),
+    );
+
+    let since: u64 = 1 << 62;
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .since((since + now).pack())
+        .build();
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+    ];
+
+    // sequence should increase by exactly one
+    let outputs_data = vec![build_time_info_cell_data(time_index, now, 2)];
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(ERROR_INVALID_SEQUENCE).input_type_script(0)
+    );
+}
+
+#[test]
+fn test_error_update_with_unchained_cell_data() {
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    let type_script = context.
+        build_script(&out_point, build_time_info_args(&out_point, vec![])).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    let now = Utc::now().timestamp() as u64;
+    let time_index = 0;
+
+    // prepare cells
+    let input_data = build_time_info_cell_data(time_index, now - TIME_INFO_UPDATE_INTERVAL, 0);
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        input_data.clone(),
+    );
+
+    let since: u64 = 1 << 62;
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .since((since + now).pack())
+        .build();
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+    ];
+
+    // output cell data should carry the hash of input cell data
+    let outputs_data = vec![build_next_time_info_cell_data(
+        &build_time_info_cell_data(time_index, now - TIME_INFO_UPDATE_INTERVAL * 2, 0),
+        time_index,
+        now,
+    )];
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(ERROR_INVALID_PREV_DATA_HASH).input_type_script(0)
+    );
+}
+
+#[test]
+fn test_success_update_with_header_dep_block() {
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    let tolerance: u32 = 30;
+    let type_script = context.
+        build_script(
+            &out_point,
+            build_time_info_args(
+                &out_point,
+                vec![(ARGS_FIELD_HEADER_DEP_TOLERANCE, Bytes::from(tolerance.to_be_bytes().to_vec()))],
+            ),
+        ).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    let now = Utc::now().timestamp() as u64;
+    let time_index = 0;
+
+    // prepare header, the timestamp of header is in milliseconds
+    let header = HeaderBuilder::default()
+        .timestamp(((now - tolerance as u64) * 1000).pack())
+        .number(1000u64.pack())
+        .epoch(42u64.pack())
+        .build();
+    context.insert_header(header.clone());
+
+    // prepare cells
+    let input_data = build_time_info_cell_data(time_index, now - TIME_INFO_UPDATE_INTERVAL, 0);
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        input_data.clone(),
+    );
+
+    let since: u64 = 1 << 62;
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .since((since + now).pack())
+        .build();
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(500u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+    ];
+
+    // block number and epoch of header dep are recorded with timestamp
+    let outputs_data = vec![build_time_info_cell_data_with_header_dep_block(
+        build_next_time_info_cell_data(&input_data, time_index, now),
+        1000,
+        42,
+    )];
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .header_dep(header.hash())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_error_update_with_mismatched_header_dep_block() {
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    let tolerance: u32 = 30;
+    let type_script = context.
+        build_script(
+            &out_point,
+            build_time_info_args(
+                &out_point,
+                vec![(ARGS_FIELD_HEADER_DEP_TOLERANCE, Bytes::from(tolerance.to_be_bytes().to_vec()))],
+            ),
+        ).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    let now = Utc::now().timestamp() as u64;
+    let time_index = 0;
+
+    // prepare header, the timestamp of header is in milliseconds
+    let header = HeaderBuilder::default()
+        .timestamp(((now - tolerance as u64) * 1000).pack())
+        .number(1000u64.pack())
+        .epoch(42u64.pack())
+        .build();
+    context.insert_header(header.clone());
+
+    // prepare cells
+    let input_data = build_time_info_cell_data(time_index, now - TIME_INFO_UPDATE_INTERVAL, 0);
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        input_data.clone(),
+    );
+
+    let since: u64 = 1 << 62;
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .since((since + now).pack())
+        .build();
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(500u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+    ];
+
+    // block number is not the one of header dep
+    let outputs_data = vec![build_time_info_cell_data_with_header_dep_block(
+        build_next_time_info_cell_data(&input_data, time_index, now),
+        1001,
+        42,
+    )];
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .header_dep(header.hash())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(ERROR_INVALID_HEADER_DEP_BLOCK).input_type_script(0)
+    );
+}
+
+#[test]
+fn test_error_update_with_header_dep_block_without_tolerance() {
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    // header dep tolerance is not set in args, so timestamp is not anchored to the header dep
+    let tolerance: u32 = 30;
+    let type_script = context.
+        build_script(&out_point, build_time_info_args(&out_point, vec![])).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    let now = Utc::now().timestamp() as u64;
+    let time_index = 0;
+
+    // prepare header, the timestamp of header is in milliseconds
+    let header = HeaderBuilder::default()
+        .timestamp(((now - tolerance as u64) * 1000).pack())
+        .number(1000u64.pack())
+        .epoch(42u64.pack())
+        .build();
+    context.insert_header(header.clone());
+
+    // prepare cells
+    let input_data = build_time_info_cell_data(time_index, now - TIME_INFO_UPDATE_INTERVAL, 0);
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        input_data.clone(),
+    );
+
+    let since: u64 = 1 << 62;
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .since((since + now).pack())
+        .build();
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(500u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+    ];
+
+    // block number and epoch of header dep are recorded with a timestamp which is not attested by the header dep
+    let outputs_data = vec![build_time_info_cell_data_with_header_dep_block(
+        build_next_time_info_cell_data(&input_data, time_index, now),
+        1000,
+        42,
+    )];
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .header_dep(header.hash())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(ERROR_INVALID_HEADER_DEP_BLOCK).input_type_script(0)
+    );
+}
+
+#[test]
+fn test_error_update_with_backward_header_dep_block() {
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+    let tolerance: u32 = 30;
+    let type_script = context.
+        build_script(
+            &out_point,
+            build_time_info_args(
+                &out_point,
+                vec![(ARGS_FIELD_HEADER_DEP_TOLERANCE, Bytes::from(tolerance.to_be_bytes().to_vec()))],
+            ),
+        ).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    let now = Utc::now().timestamp() as u64;
+    let time_index = 0;
+
+    // prepare header, the timestamp of header is in milliseconds
+    let header = HeaderBuilder::default()
+        .timestamp(((now - tolerance as u64) * 1000).pack())
+        .number(1000u64.pack())
+        .epoch(42u64.pack())
+        .build();
+    context.insert_header(header.clone());
+
+    // prepare cells
+    let input_data = build_time_info_cell_data_with_header_dep_block(
+        build_time_info_cell_data(time_index, now - TIME_INFO_UPDATE_INTERVAL, 0),
+        2000,
+        42,
+    );
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        input_data.clone(),
+    );
+
+    let since: u64 = 1 << 62;
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .since((since + now).pack())
+        .build();
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(500u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+    ];
+
+    // block number of header dep is lower than the one recorded in input cell data
+    let outputs_data = vec![build_time_info_cell_data_with_header_dep_block(
+        build_next_time_info_cell_data(&input_data, time_index, now),
+        1000,
+        42,
+    )];
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .header_dep(header.hash())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(ERROR_INVALID_HEADER_DEP_BLOCK).input_type_script(0)
+    );
+}
+
+#[test]
+fn test_success_update_pause_by_admin() {
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let admin_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![1]))
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    let type_script = context.
+        build_script(&out_point, build_time_info_args(
+            &out_point,
+            vec![(ARGS_FIELD_ADMIN_LOCK_HASH, admin_lock_script.calc_script_hash().as_bytes())],
+        )).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    let now = Utc::now().timestamp() as u64;
+    let time_index = 0;
+
+    // prepare cells
+    let input_data = build_time_info_cell_data(time_index, now - TIME_INFO_UPDATE_INTERVAL, 0);
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        input_data.clone(),
+    );
+    let admin_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(admin_lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+
+    let since: u64 = 1 << 62;
+    let inputs = vec![
+        CellInput::new_builder()
+            .previous_output(input_out_point)
+            .since((since + now).pack())
+            .build(),
+        CellInput::new_builder()
+            .previous_output(admin_out_point)
+            .build(),
+    ];
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+    ];
+
+    // admin pauses time info cell without changing its timestamp
+    let outputs_data = vec![build_paused_time_info_cell_data(build_next_time_info_cell_data(
+        &input_data,
+        time_index,
+        now - TIME_INFO_UPDATE_INTERVAL,
+    ))];
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .inputs(inputs)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_success_update_pause_legacy_cell_data_by_admin() {
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let admin_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![1]))
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    let type_script = context.
+        build_script(&out_point, build_time_info_args(
+            &out_point,
+            vec![(ARGS_FIELD_ADMIN_LOCK_HASH, admin_lock_script.calc_script_hash().as_bytes())],
+        )).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    let now = Utc::now().timestamp() as u64;
+    let time_index = 0;
+
+    // prepare cells with legacy cell data, which has no paused flag
+    let input_data = build_legacy_u64_time_info_cell_data(time_index, now - TIME_INFO_UPDATE_INTERVAL);
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        input_data.clone(),
+    );
+    let admin_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(admin_lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+
+    let since: u64 = 1 << 62;
+    let inputs = vec![
+        CellInput::new_builder()
+            .previous_output(input_out_point)
+            .since((since + now).pack())
+            .build(),
+        CellInput::new_builder()
+            .previous_output(admin_out_point)
+            .build(),
+    ];
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+    ];
+
+    // admin pauses time info cell in the same update which migrates it to the latest version
+    let outputs_data = vec![build_paused_time_info_cell_data(build_next_time_info_cell_data(
+        &input_data,
+        time_index,
+        now - TIME_INFO_UPDATE_INTERVAL,
+    ))];
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .inputs(inputs)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_error_update_pause_without_admin() {
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let admin_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![1]))
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    let type_script = context.
+        build_script(&out_point, build_time_info_args(
+            &out_point,
+            vec![(ARGS_FIELD_ADMIN_LOCK_HASH, admin_lock_script.calc_script_hash().as_bytes())],
+        )).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    let now = Utc::now().timestamp() as u64;
+    let time_index = 0;
+
+    // prepare cells
+    let input_data = build_time_info_cell_data(time_index, now - TIME_INFO_UPDATE_INTERVAL, 0);
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        input_data.clone(),
+    );
+
+    let since: u64 = 1 << 62;
+    let inputs = vec![
+        CellInput::new_builder()
+            .previous_output(input_out_point)
+            .since((since + now).pack())
+            .build(),
+    ];
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+    ];
+
+    // pausing time info cell needs admin authorization
+    let outputs_data = vec![build_paused_time_info_cell_data(build_next_time_info_cell_data(
+        &input_data,
+        time_index,
+        now - TIME_INFO_UPDATE_INTERVAL,
+    ))];
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .inputs(inputs)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(ERROR_INVALID_ADMIN_AUTHORIZATION).input_type_script(0)
+    );
+}
+
+#[test]
+fn test_error_update_while_paused() {
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("time_info_type_script");
+    let out_point = context.deploy_cell(contract_bin);
+
+    // deploy always_success script
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let admin_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![1]))
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    let type_script = context.
+        build_script(&out_point, build_time_info_args(
+            &out_point,
+            vec![(ARGS_FIELD_ADMIN_LOCK_HASH, admin_lock_script.calc_script_hash().as_bytes())],
+        )).
+        expect("script");
+    let type_script_dep = CellDep::new_builder().
+        out_point(out_point).
+        build();
+
+    let now = Utc::now().timestamp() as u64;
+    let time_index = 0;
+
+    // prepare cells
+    let input_data = build_paused_time_info_cell_data(build_time_info_cell_data(time_index, now - TIME_INFO_UPDATE_INTERVAL, 0));
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        input_data.clone(),
+    );
+
+    let since: u64 = 1 << 62;
+    let inputs = vec![
+        CellInput::new_builder()
+            .previous_output(input_out_point)
+            .since((since + now).pack())
+            .build(),
+    ];
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+    ];
+
+    // paused time info cell cannot be updated until admin resumes it
+    let outputs_data = vec![build_paused_time_info_cell_data(build_next_time_info_cell_data(&input_data, time_index, now))];
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .inputs(inputs)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(ERROR_TIME_INFO_PAUSED).input_type_script(0)
+    );
+}
@@ -0,0 +1,251 @@
+use core::convert::TryFrom;
+
+use crate::error::ParseError;
+
+pub const TIME_INFO_CELL_DATA_N: u8 = 12;
+pub const TIME_INFO_UPDATE_INTERVAL: u32 = 60;
+
+//script args = out_point(txHash + index) | field | field ...
+//every optional field = field_id as u8 | value_len as u8 | value
+pub const TIME_INFO_ARGS_OUT_POINT_LEN: usize = 36;
+pub const ARGS_FIELD_ADMIN_LOCK_HASH: u8 = 1;
+pub const ARGS_FIELD_OPERATOR_LOCK_HASH: u8 = 2;
+pub const ARGS_FIELD_MIN_UPDATE_INTERVAL: u8 = 3;
+pub const ARGS_FIELD_MAX_TIMESTAMP_STEP: u8 = 4;
+pub const ARGS_FIELD_HEADER_DEP_TOLERANCE: u8 = 5;
+pub const ARGS_FIELD_SINCE_TOLERANCE: u8 = 6;
+pub const ARGS_FIELD_RING_SIZE: u8 = 7;
+pub const ARGS_FIELD_RING_INDEX: u8 = 8;
+pub const ARGS_FIELD_TIME_INDEX_STATE_TYPE_HASH: u8 = 9;
+pub const ARGS_FIELD_FEE_ALLOWANCE: u8 = 10;
+pub const ARGS_FIELD_ORACLE_KEY: u8 = 11;
+pub const ARGS_FIELD_SECP256K1_LIB_CODE_HASH: u8 = 12;
+pub const ARGS_FIELD_ORACLE_QUORUM: u8 = 13;
+pub const ARGS_FIELD_ORACLE_SPREAD: u8 = 14;
+
+//oracle key = key_type as u8 | blake160 of pubkey, the pubkey of secp256k1 is recovered from signature,
+//and the pubkey of ed25519 is carried with signature in witness
+pub const ORACLE_KEY_TYPE_SECP256K1: u8 = 0;
+pub const ORACLE_KEY_TYPE_ED25519: u8 = 1;
+pub const SECP256K1_PUBKEY_HASH_LEN: usize = 20;
+pub const ORACLE_KEY_LEN: usize = 1 + SECP256K1_PUBKEY_HASH_LEN;
+
+//oracle quorum = threshold M as u8 | oracle key * N, N is limited by the max field value length
+pub const ORACLE_QUORUM_MAX_SIZE: usize = (u8::MAX as usize - 1) / ORACLE_KEY_LEN;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct OracleKey {
+    pub key_type: u8,
+    pub pubkey_hash: [u8; SECP256K1_PUBKEY_HASH_LEN],
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct OracleQuorum {
+    pub threshold: u8,
+    size: u8,
+    keys: [OracleKey; ORACLE_QUORUM_MAX_SIZE],
+}
+
+impl OracleQuorum {
+    pub fn keys(&self) -> &[OracleKey] {
+        &self.keys[..self.size as usize]
+    }
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct TimeInfoArgs {
+    pub admin_lock_hash: Option<[u8; 32]>,
+    pub operator_lock_hash: Option<[u8; 32]>,
+    pub min_update_interval: Option<u32>,
+    pub max_timestamp_step: Option<u32>,
+    pub header_dep_tolerance: Option<u32>,
+    pub since_tolerance: Option<u32>,
+    pub ring_size: Option<u8>,
+    pub ring_index: Option<u8>,
+    pub time_index_state_type_hash: Option<[u8; 32]>,
+    pub fee_allowance: Option<u64>,
+    pub oracle_key: Option<OracleKey>,
+    pub secp256k1_lib_code_hash: Option<[u8; 32]>,
+    pub oracle_quorum: Option<OracleQuorum>,
+    pub oracle_spread: Option<u32>,
+}
+
+impl TimeInfoArgs {
+    pub fn min_update_interval(&self) -> u32 {
+        self.min_update_interval.unwrap_or(TIME_INFO_UPDATE_INTERVAL)
+    }
+
+    pub fn ring_size(&self) -> u8 {
+        self.ring_size.unwrap_or(TIME_INFO_CELL_DATA_N)
+    }
+}
+
+pub(crate) fn parse_hash(value: &[u8]) -> Result<[u8; 32], ParseError> {
+    <[u8; 32]>::try_from(value).map_err(|_| ParseError::InvalidArgument)
+}
+
+fn parse_u8(value: &[u8]) -> Result<u8, ParseError> {
+    match value {
+        [value] => Ok(*value),
+        _ => Err(ParseError::InvalidArgument),
+    }
+}
+
+fn parse_u32(value: &[u8]) -> Result<u32, ParseError> {
+    <[u8; 4]>::try_from(value).
+        map(u32::from_be_bytes).
+        map_err(|_| ParseError::InvalidArgument)
+}
+
+fn parse_u64(value: &[u8]) -> Result<u64, ParseError> {
+    <[u8; 8]>::try_from(value).
+        map(u64::from_be_bytes).
+        map_err(|_| ParseError::InvalidArgument)
+}
+
+fn parse_oracle_key(value: &[u8]) -> Result<OracleKey, ParseError> {
+    match value.split_first() {
+        //other key types have no verifier on chain, so they are rejected
+        Some((&key_type, pubkey_hash))
+            if key_type == ORACLE_KEY_TYPE_SECP256K1 || key_type == ORACLE_KEY_TYPE_ED25519 => Ok(OracleKey {
+            key_type,
+            pubkey_hash: <[u8; SECP256K1_PUBKEY_HASH_LEN]>::try_from(pubkey_hash).
+                map_err(|_| ParseError::InvalidArgument)?,
+        }),
+        _ => Err(ParseError::InvalidArgument),
+    }
+}
+
+//threshold should be in [1, N], and every oracle of quorum should be distinct, attestation of quorum
+//has a fixed length secp256k1 signature, so every oracle of quorum should be secp256k1
+fn parse_oracle_quorum(value: &[u8]) -> Result<OracleQuorum, ParseError> {
+    let (threshold, keys) = match value.split_first() {
+        Some((threshold, keys)) => (*threshold, keys),
+        None => return Err(ParseError::InvalidArgument),
+    };
+    if keys.is_empty() || keys.len() % ORACLE_KEY_LEN != 0 {
+        return Err(ParseError::InvalidArgument);
+    }
+    let mut oracle_quorum = OracleQuorum { threshold, ..Default::default() };
+    for key in keys.chunks(ORACLE_KEY_LEN) {
+        let oracle_key = parse_oracle_key(key)?;
+        if oracle_key.key_type != ORACLE_KEY_TYPE_SECP256K1 || oracle_quorum.keys().contains(&oracle_key) {
+            return Err(ParseError::InvalidArgument);
+        }
+        match oracle_quorum.keys.get_mut(oracle_quorum.size as usize) {
+            Some(key) => *key = oracle_key,
+            None => return Err(ParseError::InvalidArgument),
+        }
+        oracle_quorum.size += 1;
+    }
+    if threshold == 0 || threshold > oracle_quorum.size {
+        return Err(ParseError::InvalidArgument);
+    }
+    Ok(oracle_quorum)
+}
+
+//split optional fields into (field_id, value, rest), truncated field is invalid
+pub(crate) fn next_args_field(fields: &[u8]) -> Result<(u8, &[u8], &[u8]), ParseError> {
+    let (field_id, value_len, fields) = match fields {
+        [field_id, value_len, fields @ ..] => (*field_id, *value_len as usize, fields),
+        _ => return Err(ParseError::InvalidArgument),
+    };
+    if fields.len() < value_len {
+        return Err(ParseError::InvalidArgument);
+    }
+    let (value, rest) = fields.split_at(value_len);
+    Ok((field_id, value, rest))
+}
+
+//split script args into (out_point, optional fields)
+pub(crate) fn split_args_out_point(script_args: &[u8]) -> Result<(&[u8], &[u8]), ParseError> {
+    if script_args.len() < TIME_INFO_ARGS_OUT_POINT_LEN {
+        return Err(ParseError::InvalidArgument);
+    }
+    Ok(script_args.split_at(TIME_INFO_ARGS_OUT_POINT_LEN))
+}
+
+pub fn parse_time_info_args(script_args: &[u8]) -> Result<TimeInfoArgs, ParseError> {
+    let (_, mut fields) = split_args_out_point(script_args)?;
+    let mut time_info_args = TimeInfoArgs::default();
+    while !fields.is_empty() {
+        let (field_id, value, rest) = next_args_field(fields)?;
+        match field_id {
+            ARGS_FIELD_ADMIN_LOCK_HASH if time_info_args.admin_lock_hash.is_none() => {
+                time_info_args.admin_lock_hash = Some(parse_hash(value)?);
+            }
+            ARGS_FIELD_OPERATOR_LOCK_HASH if time_info_args.operator_lock_hash.is_none() => {
+                time_info_args.operator_lock_hash = Some(parse_hash(value)?);
+            }
+            ARGS_FIELD_MIN_UPDATE_INTERVAL if time_info_args.min_update_interval.is_none() => {
+                time_info_args.min_update_interval = Some(parse_u32(value)?);
+            }
+            ARGS_FIELD_MAX_TIMESTAMP_STEP if time_info_args.max_timestamp_step.is_none() => {
+                time_info_args.max_timestamp_step = Some(parse_u32(value)?);
+            }
+            ARGS_FIELD_HEADER_DEP_TOLERANCE if time_info_args.header_dep_tolerance.is_none() => {
+                time_info_args.header_dep_tolerance = Some(parse_u32(value)?);
+            }
+            ARGS_FIELD_SINCE_TOLERANCE if time_info_args.since_tolerance.is_none() => {
+                time_info_args.since_tolerance = Some(parse_u32(value)?);
+            }
+            ARGS_FIELD_RING_SIZE if time_info_args.ring_size.is_none() => {
+                //ring should have one time info cell at least
+                let ring_size = parse_u8(value)?;
+                if ring_size == 0 {
+                    return Err(ParseError::InvalidArgument);
+                }
+                time_info_args.ring_size = Some(ring_size);
+            }
+            ARGS_FIELD_RING_INDEX if time_info_args.ring_index.is_none() => {
+                time_info_args.ring_index = Some(parse_u8(value)?);
+            }
+            ARGS_FIELD_TIME_INDEX_STATE_TYPE_HASH if time_info_args.time_index_state_type_hash.is_none() => {
+                time_info_args.time_index_state_type_hash = Some(parse_hash(value)?);
+            }
+            ARGS_FIELD_FEE_ALLOWANCE if time_info_args.fee_allowance.is_none() => {
+                time_info_args.fee_allowance = Some(parse_u64(value)?);
+            }
+            ARGS_FIELD_ORACLE_KEY if time_info_args.oracle_key.is_none() => {
+                time_info_args.oracle_key = Some(parse_oracle_key(value)?);
+            }
+            ARGS_FIELD_SECP256K1_LIB_CODE_HASH if time_info_args.secp256k1_lib_code_hash.is_none() => {
+                time_info_args.secp256k1_lib_code_hash = Some(parse_hash(value)?);
+            }
+            ARGS_FIELD_ORACLE_QUORUM if time_info_args.oracle_quorum.is_none() => {
+                time_info_args.oracle_quorum = Some(parse_oracle_quorum(value)?);
+            }
+            ARGS_FIELD_ORACLE_SPREAD if time_info_args.oracle_spread.is_none() => {
+                time_info_args.oracle_spread = Some(parse_u32(value)?);
+            }
+            //unknown or duplicated field
+            _ => return Err(ParseError::InvalidArgument),
+        }
+        fields = rest;
+    }
+
+    //every update moves timestamp by min update interval at least, so a smaller max timestamp step
+    //would make time info cell never updatable again
+    if let Some(max_timestamp_step) = time_info_args.max_timestamp_step {
+        if max_timestamp_step < time_info_args.min_update_interval() {
+            return Err(ParseError::InvalidArgument);
+        }
+    }
+
+    //secp256k1 oracle signature is verified by the secp256k1 library in cell deps, ed25519 one is verified by script
+    let has_secp256k1_oracle = time_info_args.oracle_quorum.is_some() || matches!(
+        time_info_args.oracle_key,
+        Some(OracleKey { key_type: ORACLE_KEY_TYPE_SECP256K1, .. })
+    );
+    if has_secp256k1_oracle && time_info_args.secp256k1_lib_code_hash.is_none() {
+        return Err(ParseError::InvalidArgument);
+    }
+    //single oracle and oracle quorum are exclusive, and spread only applies to quorum
+    if time_info_args.oracle_key.is_some() && time_info_args.oracle_quorum.is_some() {
+        return Err(ParseError::InvalidArgument);
+    }
+    if time_info_args.oracle_spread.is_some() && time_info_args.oracle_quorum.is_none() {
+        return Err(ParseError::InvalidArgument);
+    }
+    Ok(time_info_args)
+}
@@ -0,0 +1,38 @@
+use core::convert::TryFrom;
+
+use blake2b_ref::Blake2bBuilder;
+
+use crate::data::TimeInfoData;
+
+const CKB_HASH_PERSONALIZATION: &[u8] = b"ckb-default-hash";
+
+//the previous data hash of new time info cell, which has no previous cell data
+pub const GENESIS_PREV_DATA_HASH: [u8; 32] = [0; 32];
+
+pub fn ckb_blake2b_256(data: &[u8]) -> [u8; 32] {
+    let mut hash = [0_u8; 32];
+    let mut blake2b = Blake2bBuilder::new(32).personal(CKB_HASH_PERSONALIZATION).build();
+    blake2b.update(data);
+    blake2b.finalize(&mut hash);
+    hash
+}
+
+//check historical cell data, ordered from the oldest to the newest, is chained to the current head,
+//every cell data after the oldest one should carry the hash of the cell data before it
+pub fn verify_time_info_history(history: &[&[u8]], head: &[u8]) -> bool {
+    let mut prev_data = match history.first() {
+        Some(prev_data) => *prev_data,
+        None => return false,
+    };
+    for cell_data in history.iter().skip(1).chain(core::iter::once(&head)) {
+        let prev_data_hash = match TimeInfoData::try_from(*cell_data) {
+            Ok(time_info_data) => time_info_data.prev_data_hash,
+            Err(_) => return false,
+        };
+        if prev_data_hash != Some(ckb_blake2b_256(prev_data)) {
+            return false;
+        }
+        prev_data = cell_data;
+    }
+    true
+}
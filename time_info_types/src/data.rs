@@ -0,0 +1,258 @@
+use core::convert::TryFrom;
+
+use crate::error::ParseError;
+use crate::oracle::{OracleEntries, ORACLE_ENTRY_LEN};
+
+//cell data layout registry, every layout has a version
+//version 0: index as u8 | timestamp as u32 (5 bytes), legacy layout without version byte
+//version 1: index as u8 | timestamp as u64 (9 bytes), legacy layout without version byte
+//version 2: version as u8 | flags as u8 | index as u8 | timestamp as u64 (11 bytes) | optional fields
+//version 3: version as u8 | flags as u8 | index as u8 | timestamp as u64 | sequence as u64 (19 bytes) | optional fields
+//version 4: version as u8 | flags as u8 | index as u8 | timestamp as u64 | sequence as u64 | prev data hash (51 bytes)
+//           | optional fields
+//legacy layouts are detected by the length of cell data and the others by the leading version byte,
+//so a new layout should never be 5 or 9 bytes long
+pub struct CellDataLayout {
+    pub version: u8,
+    pub len: usize,
+    pub index_offset: usize,
+    pub timestamp_len: usize,
+    pub sequence_len: usize,
+    pub prev_data_hash_len: usize,
+}
+
+pub const CELL_DATA_VERSION_0: u8 = 0;
+pub const CELL_DATA_VERSION_1: u8 = 1;
+pub const CELL_DATA_VERSION_2: u8 = 2;
+pub const CELL_DATA_VERSION_3: u8 = 3;
+pub const CELL_DATA_VERSION_4: u8 = 4;
+pub const CELL_DATA_LEN_0: usize = 5;
+pub const CELL_DATA_LEN_1: usize = 9;
+pub const CELL_DATA_LEN_2: usize = 11;
+pub const CELL_DATA_LEN_3: usize = 19;
+pub const CELL_DATA_LEN_4: usize = 51;
+//new time info cell should use the latest layout
+pub const TIME_INFO_CELL_DATA_VERSION: u8 = CELL_DATA_VERSION_4;
+pub const TIME_INFO_CELL_DATA_LEN: usize = CELL_DATA_LEN_4;
+
+//every flag since version 2 tells an optional field follows the fixed fields, in the order of flag bits
+//oracle entries: the timestamps of oracles which fed the update, and timestamp is their median
+//header dep block: block number as u64 | epoch as u64 of the first header dep, attested with timestamp
+//paused: no optional field, consumers should not trust the timestamp of paused time info cell
+pub const CELL_DATA_FLAG_ORACLE_ENTRIES: u8 = 0b0000_0001;
+pub const CELL_DATA_FLAG_HEADER_DEP_BLOCK: u8 = 0b0000_0010;
+pub const CELL_DATA_FLAG_PAUSED: u8 = 0b0000_0100;
+pub const CELL_DATA_FLAGS: u8 = CELL_DATA_FLAG_ORACLE_ENTRIES | CELL_DATA_FLAG_HEADER_DEP_BLOCK | CELL_DATA_FLAG_PAUSED;
+pub const HEADER_DEP_BLOCK_LEN: usize = 8 + 8;
+
+pub static CELL_DATA_LAYOUTS: [CellDataLayout; 5] = [
+    CellDataLayout {
+        version: CELL_DATA_VERSION_0, len: CELL_DATA_LEN_0, index_offset: 0,
+        timestamp_len: 4, sequence_len: 0, prev_data_hash_len: 0,
+    },
+    CellDataLayout {
+        version: CELL_DATA_VERSION_1, len: CELL_DATA_LEN_1, index_offset: 0,
+        timestamp_len: 8, sequence_len: 0, prev_data_hash_len: 0,
+    },
+    CellDataLayout {
+        version: CELL_DATA_VERSION_2, len: CELL_DATA_LEN_2, index_offset: 2,
+        timestamp_len: 8, sequence_len: 0, prev_data_hash_len: 0,
+    },
+    CellDataLayout {
+        version: CELL_DATA_VERSION_3, len: CELL_DATA_LEN_3, index_offset: 2,
+        timestamp_len: 8, sequence_len: 8, prev_data_hash_len: 0,
+    },
+    CellDataLayout {
+        version: CELL_DATA_VERSION_4, len: CELL_DATA_LEN_4, index_offset: 2,
+        timestamp_len: 8, sequence_len: 8, prev_data_hash_len: 32,
+    },
+];
+
+//the version of time info cell can only be changed on update by these migrations (from, to)
+pub static CELL_DATA_MIGRATIONS: [(u8, u8); 10] = [
+    (CELL_DATA_VERSION_0, CELL_DATA_VERSION_1),
+    (CELL_DATA_VERSION_0, CELL_DATA_VERSION_2),
+    (CELL_DATA_VERSION_1, CELL_DATA_VERSION_2),
+    (CELL_DATA_VERSION_0, CELL_DATA_VERSION_3),
+    (CELL_DATA_VERSION_1, CELL_DATA_VERSION_3),
+    (CELL_DATA_VERSION_2, CELL_DATA_VERSION_3),
+    (CELL_DATA_VERSION_0, CELL_DATA_VERSION_4),
+    (CELL_DATA_VERSION_1, CELL_DATA_VERSION_4),
+    (CELL_DATA_VERSION_2, CELL_DATA_VERSION_4),
+    (CELL_DATA_VERSION_3, CELL_DATA_VERSION_4),
+];
+
+pub fn get_cell_data_layout(cell_data: &[u8]) -> Result<&'static CellDataLayout, ParseError> {
+    let version = match (cell_data.len(), cell_data.first()) {
+        (_, None) => return Err(ParseError::EmptyCellData),
+        (CELL_DATA_LEN_0, _) => CELL_DATA_VERSION_0,
+        (CELL_DATA_LEN_1, _) => CELL_DATA_VERSION_1,
+        (_, Some(version)) => *version,
+    };
+    let layout = match CELL_DATA_LAYOUTS.iter().find(|layout| layout.version == version) {
+        Some(layout) => layout,
+        None => return Err(ParseError::UnknownCellDataVersion(version)),
+    };
+    if cell_data.len() < layout.len {
+        return Err(ParseError::InvalidCellDataLength);
+    }
+    split_optional_fields(get_cell_data_flags(layout, cell_data)?, &cell_data[layout.len..])?;
+    Ok(layout)
+}
+
+fn get_cell_data_flags(layout: &CellDataLayout, cell_data: &[u8]) -> Result<u8, ParseError> {
+    let flags = match cell_data.get(1) {
+        Some(flags) if layout.version >= CELL_DATA_VERSION_2 => *flags,
+        _ => 0,
+    };
+    if flags & !CELL_DATA_FLAGS != 0 {
+        return Err(ParseError::InvalidCellDataFlags);
+    }
+    Ok(flags)
+}
+
+//(oracle entries, header dep block)
+type OptionalFields<'a> = (Option<&'a [u8]>, Option<&'a [u8]>);
+
+//split optional fields by flags, nothing should be left
+fn split_optional_fields(flags: u8, optional_fields: &[u8]) -> Result<OptionalFields<'_>, ParseError> {
+    //length of oracle entries is given by its count
+    let oracle_entries_len = match optional_fields.first() {
+        Some(count) if flags & CELL_DATA_FLAG_ORACLE_ENTRIES != 0 => 1 + *count as usize * ORACLE_ENTRY_LEN,
+        None if flags & CELL_DATA_FLAG_ORACLE_ENTRIES != 0 => return Err(ParseError::InvalidCellDataLength),
+        _ => 0,
+    };
+    let header_dep_block_len = if flags & CELL_DATA_FLAG_HEADER_DEP_BLOCK != 0 { HEADER_DEP_BLOCK_LEN } else { 0 };
+    if optional_fields.len() != oracle_entries_len + header_dep_block_len {
+        return Err(ParseError::InvalidCellDataLength);
+    }
+    let (oracle_entries, header_dep_block) = optional_fields.split_at(oracle_entries_len);
+    Ok((
+        Some(oracle_entries).filter(|oracle_entries| !oracle_entries.is_empty()),
+        Some(header_dep_block).filter(|header_dep_block| !header_dep_block.is_empty()),
+    ))
+}
+
+/// Block of the header dep which the timestamp is attested with
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HeaderDepBlock {
+    pub block_number: u64,
+    //epoch is encoded as in block header, with the index and length of epoch
+    pub epoch: u64,
+}
+
+impl TryFrom<&[u8]> for HeaderDepBlock {
+    type Error = ParseError;
+
+    fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+        let data = <&[u8; HEADER_DEP_BLOCK_LEN]>::try_from(data).map_err(|_| ParseError::InvalidCellDataLength)?;
+        let mut block_number = [0_u8; 8];
+        block_number.copy_from_slice(&data[..8]);
+        let mut epoch = [0_u8; 8];
+        epoch.copy_from_slice(&data[8..]);
+        Ok(HeaderDepBlock { block_number: u64::from_be_bytes(block_number), epoch: u64::from_be_bytes(epoch) })
+    }
+}
+
+/// Time info cell data decoded from any registered layout
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeInfoData {
+    pub version: u8,
+    pub index: u8,
+    pub timestamp: u64,
+    //number of updates since creation, none for the layouts before version 3
+    pub sequence: Option<u64>,
+    //hash of the cell data before the last update, none for the layouts before version 4
+    pub prev_data_hash: Option<[u8; 32]>,
+    pub oracle_entries: Option<OracleEntries>,
+    pub header_dep_block: Option<HeaderDepBlock>,
+    pub paused: bool,
+}
+
+impl TimeInfoData {
+    pub fn new(index: u8, timestamp: u64, sequence: u64, prev_data_hash: [u8; 32]) -> Self {
+        TimeInfoData {
+            version: TIME_INFO_CELL_DATA_VERSION,
+            index,
+            timestamp,
+            sequence: Some(sequence),
+            prev_data_hash: Some(prev_data_hash),
+            oracle_entries: None,
+            header_dep_block: None,
+            paused: false,
+        }
+    }
+}
+
+impl TryFrom<&[u8]> for TimeInfoData {
+    type Error = ParseError;
+
+    fn try_from(cell_data: &[u8]) -> Result<Self, Self::Error> {
+        let layout = get_cell_data_layout(cell_data)?;
+        let index = match cell_data.get(layout.index_offset) {
+            Some(index) => *index,
+            None => return Err(ParseError::InvalidCellDataLength),
+        };
+        let timestamp_offset = layout.index_offset + 1;
+        let timestamp_data = match cell_data.get(timestamp_offset..timestamp_offset + layout.timestamp_len) {
+            Some(timestamp_data) => timestamp_data,
+            None => return Err(ParseError::InvalidCellDataLength),
+        };
+        //legacy u32 timestamp is padded to u64
+        let timestamp = timestamp_data.iter().fold(0_u64, |timestamp, byte| timestamp << 8 | *byte as u64);
+        let sequence_offset = timestamp_offset + layout.timestamp_len;
+        let sequence = match cell_data.get(sequence_offset..sequence_offset + layout.sequence_len) {
+            Some(sequence_data) if !sequence_data.is_empty() => Some(
+                sequence_data.iter().fold(0_u64, |sequence, byte| sequence << 8 | *byte as u64),
+            ),
+            Some(_) => None,
+            None => return Err(ParseError::InvalidCellDataLength),
+        };
+        let prev_data_hash_offset = sequence_offset + layout.sequence_len;
+        let prev_data_hash = match cell_data.get(prev_data_hash_offset..prev_data_hash_offset + layout.prev_data_hash_len) {
+            Some(prev_data_hash) if !prev_data_hash.is_empty() => Some(
+                <[u8; 32]>::try_from(prev_data_hash).map_err(|_| ParseError::InvalidCellDataLength)?,
+            ),
+            Some(_) => None,
+            None => return Err(ParseError::InvalidCellDataLength),
+        };
+        let flags = get_cell_data_flags(layout, cell_data)?;
+        let optional_fields = cell_data.get(layout.len..).unwrap_or_default();
+        let (oracle_entries, header_dep_block) = split_optional_fields(flags, optional_fields)?;
+        Ok(TimeInfoData {
+            version: layout.version,
+            index,
+            timestamp,
+            sequence,
+            prev_data_hash,
+            oracle_entries: oracle_entries.map(OracleEntries::try_from).transpose()?,
+            header_dep_block: header_dep_block.map(HeaderDepBlock::try_from).transpose()?,
+            paused: flags & CELL_DATA_FLAG_PAUSED != 0,
+        })
+    }
+}
+
+//decode cell data for consumers, the timestamp of paused time info cell should not be trusted
+pub fn parse_active_time_info_data(cell_data: &[u8]) -> Result<TimeInfoData, ParseError> {
+    let time_info_data = TimeInfoData::try_from(cell_data)?;
+    if time_info_data.paused {
+        return Err(ParseError::TimeInfoPaused);
+    }
+    Ok(time_info_data)
+}
+
+//encode cell data with the latest layout
+pub fn encode_time_info_cell_data(
+    index: u8,
+    timestamp: u64,
+    sequence: u64,
+    prev_data_hash: &[u8; 32],
+) -> [u8; TIME_INFO_CELL_DATA_LEN] {
+    let mut cell_data = [0_u8; TIME_INFO_CELL_DATA_LEN];
+    cell_data[0] = TIME_INFO_CELL_DATA_VERSION;
+    cell_data[2] = index;
+    cell_data[3..11].copy_from_slice(&timestamp.to_be_bytes());
+    cell_data[11..19].copy_from_slice(&sequence.to_be_bytes());
+    cell_data[19..].copy_from_slice(prev_data_hash);
+    cell_data
+}
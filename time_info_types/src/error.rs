@@ -0,0 +1,70 @@
+//error codes of time info type script, the exit code of script is one of them
+pub const ERROR_INDEX_OUT_OF_BOUND: i8 = 50;
+pub const ERROR_ITEM_MISSING: i8 = 51;
+pub const ERROR_LENGTH_NOT_ENOUGH: i8 = 52;
+pub const ERROR_ENCODING: i8 = 53;
+pub const ERROR_INVALID_ARGUMENT: i8 = 54;
+pub const ERROR_INVALID_CELL_DATA: i8 = 55;
+pub const ERROR_INVALID_TIME_INFO_INPUT: i8 = 56;
+pub const ERROR_INVALID_TIME_INFO_OUTPUT: i8 = 57;
+pub const ERROR_INVALID_TIME_SINCE: i8 = 58;
+pub const ERROR_INVALID_TIMESTAMP: i8 = 59;
+pub const ERROR_INVALID_TIME_INDEX: i8 = 60;
+pub const ERROR_INVALID_ARGS_OUT_POINT: i8 = 61;
+pub const ERROR_DESTROY_NOT_ALLOWED: i8 = 62;
+pub const ERROR_INVALID_ADMIN_AUTHORIZATION: i8 = 63;
+pub const ERROR_INVALID_OPERATOR_AUTHORIZATION: i8 = 64;
+pub const ERROR_INVALID_UPDATE_INTERVAL: i8 = 65;
+pub const ERROR_INVALID_TIMESTAMP_STEP: i8 = 66;
+pub const ERROR_INVALID_CELL_DATA_VERSION: i8 = 67;
+pub const ERROR_INVALID_HEADER_DEP: i8 = 68;
+pub const ERROR_INVALID_HEADER_DEP_TIMESTAMP: i8 = 69;
+pub const ERROR_INVALID_SINCE_FLAGS: i8 = 70;
+pub const ERROR_RELATIVE_SINCE: i8 = 71;
+pub const ERROR_DUPLICATE_TIME_INDEX: i8 = 72;
+pub const ERROR_INVALID_RING_GENESIS: i8 = 73;
+pub const ERROR_INVALID_TIME_INDEX_STATE: i8 = 74;
+pub const ERROR_UNKNOWN_SYS_ERROR: i8 = 75;
+pub const ERROR_INVALID_CELL_LOCK: i8 = 76;
+pub const ERROR_INVALID_CELL_CAPACITY: i8 = 77;
+pub const ERROR_INVALID_ORACLE_LIB: i8 = 78;
+pub const ERROR_INVALID_ORACLE_WITNESS: i8 = 79;
+pub const ERROR_INVALID_ORACLE_SIGNATURE: i8 = 80;
+pub const ERROR_INVALID_ORACLE_QUORUM: i8 = 81;
+pub const ERROR_INVALID_ORACLE_TIMESTAMP: i8 = 82;
+pub const ERROR_INVALID_ORACLE_ENTRIES: i8 = 83;
+pub const ERROR_INVALID_SEQUENCE: i8 = 84;
+pub const ERROR_INVALID_PREV_DATA_HASH: i8 = 85;
+pub const ERROR_INVALID_HEADER_DEP_BLOCK: i8 = 86;
+pub const ERROR_TIME_INFO_PAUSED: i8 = 87;
+
+//error codes of time index state type script
+pub const STATE_ERROR_INDEX_OUT_OF_BOUND: i8 = 50;
+pub const STATE_ERROR_ITEM_MISSING: i8 = 51;
+pub const STATE_ERROR_LENGTH_NOT_ENOUGH: i8 = 52;
+pub const STATE_ERROR_ENCODING: i8 = 53;
+pub const STATE_ERROR_INVALID_ARGUMENT: i8 = 54;
+pub const STATE_ERROR_INVALID_CELL_DATA: i8 = 55;
+pub const STATE_ERROR_INVALID_TIME_INDEX_STATE_INPUT: i8 = 56;
+pub const STATE_ERROR_INVALID_TIME_INDEX_STATE_OUTPUT: i8 = 57;
+pub const STATE_ERROR_INVALID_TIME_INDEX: i8 = 58;
+pub const STATE_ERROR_INVALID_RING_SIZE: i8 = 59;
+pub const STATE_ERROR_INVALID_ARGS_OUT_POINT: i8 = 60;
+pub const STATE_ERROR_DESTROY_NOT_ALLOWED: i8 = 61;
+pub const STATE_ERROR_INVALID_ADMIN_AUTHORIZATION: i8 = 62;
+pub const STATE_ERROR_INVALID_OPERATOR_AUTHORIZATION: i8 = 63;
+pub const STATE_ERROR_UNKNOWN_SYS_ERROR: i8 = 64;
+pub const STATE_ERROR_INVALID_TIME_INFO_UPDATE: i8 = 65;
+
+/// Error of parsing args or cell data, scripts map it to their own error
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseError {
+    InvalidArgument,
+    EmptyCellData,
+    UnknownCellDataVersion(u8),
+    InvalidCellDataLength,
+    InvalidCellDataFlags,
+    InvalidOracleWitness,
+    InvalidOracleEntries,
+    TimeInfoPaused,
+}
@@ -0,0 +1,18 @@
+//! Constants, layouts and error codes shared by time info type script, time index state type script,
+//! the tests and off-chain tools.
+//!
+//! See `args.rs` for the args layout of time info type script.
+//! See `data.rs` for the cell data layouts of time info cell.
+//! See `chain.rs` for the hash chain of time info cell data.
+//! See `oracle.rs` for the message and witness signed by oracles.
+//! See `state.rs` for the args and cell data of time index state cell.
+//! See `error.rs` for the error code table.
+
+#![no_std]
+
+pub mod args;
+pub mod chain;
+pub mod data;
+pub mod error;
+pub mod oracle;
+pub mod state;
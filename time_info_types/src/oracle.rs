@@ -0,0 +1,129 @@
+use core::convert::TryFrom;
+
+use crate::args::ORACLE_QUORUM_MAX_SIZE;
+use crate::error::ParseError;
+
+//the message signed by oracle for an update, index as u8 | timestamp as u64 | previous out point
+pub const ORACLE_MESSAGE_LEN: usize = 1 + 8 + 36;
+
+//every attestation of quorum = oracle index as u8 | timestamp as u64 | signature
+pub const ORACLE_SIGNATURE_LEN: usize = 65;
+pub const ORACLE_ATTESTATION_LEN: usize = 1 + 8 + ORACLE_SIGNATURE_LEN;
+
+//oracle entries in cell data = count K as u8 | (oracle index as u8 | timestamp as u64) * K
+pub const ORACLE_ENTRY_LEN: usize = 1 + 8;
+pub const ORACLE_ENTRIES_MAX_COUNT: usize = ORACLE_QUORUM_MAX_SIZE;
+
+pub fn encode_oracle_message(index: u8, timestamp: u64, previous_out_point: &[u8; 36]) -> [u8; ORACLE_MESSAGE_LEN] {
+    let mut message = [0_u8; ORACLE_MESSAGE_LEN];
+    message[0] = index;
+    message[1..9].copy_from_slice(&timestamp.to_be_bytes());
+    message[9..].copy_from_slice(previous_out_point);
+    message
+}
+
+/// Timestamp signed by one oracle of quorum, oracle index is the position of its key in args
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OracleAttestation {
+    pub oracle_index: u8,
+    pub timestamp: u64,
+    pub signature: [u8; ORACLE_SIGNATURE_LEN],
+}
+
+impl TryFrom<&[u8]> for OracleAttestation {
+    type Error = ParseError;
+
+    fn try_from(attestation: &[u8]) -> Result<Self, Self::Error> {
+        let attestation = <&[u8; ORACLE_ATTESTATION_LEN]>::try_from(attestation).
+            map_err(|_| ParseError::InvalidOracleWitness)?;
+        let mut timestamp = [0_u8; 8];
+        timestamp.copy_from_slice(&attestation[1..9]);
+        let mut signature = [0_u8; ORACLE_SIGNATURE_LEN];
+        signature.copy_from_slice(&attestation[9..]);
+        Ok(OracleAttestation {
+            oracle_index: attestation[0],
+            timestamp: u64::from_be_bytes(timestamp),
+            signature,
+        })
+    }
+}
+
+//split witness into attestations, oracle index should be strictly increasing so every oracle attests once
+pub fn parse_oracle_attestations(
+    witness: &[u8],
+) -> Result<impl Iterator<Item = OracleAttestation> + '_, ParseError> {
+    if witness.is_empty() || !witness.chunks_exact(ORACLE_ATTESTATION_LEN).remainder().is_empty() {
+        return Err(ParseError::InvalidOracleWitness);
+    }
+    let mut last_oracle_index = None;
+    for attestation in witness.chunks(ORACLE_ATTESTATION_LEN) {
+        let oracle_index = Some(attestation[0]);
+        if oracle_index <= last_oracle_index {
+            return Err(ParseError::InvalidOracleWitness);
+        }
+        last_oracle_index = oracle_index;
+    }
+    Ok(witness.chunks(ORACLE_ATTESTATION_LEN).filter_map(|attestation| OracleAttestation::try_from(attestation).ok()))
+}
+
+//lower median, so the median is always one of the attested timestamps
+pub fn median_timestamp(timestamps: &mut [u64]) -> Option<u64> {
+    if timestamps.is_empty() {
+        return None;
+    }
+    timestamps.sort_unstable();
+    Some(timestamps[(timestamps.len() - 1) / 2])
+}
+
+/// Timestamp of one oracle which fed the last update of time info cell
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct OracleEntry {
+    pub oracle_index: u8,
+    pub timestamp: u64,
+}
+
+/// Oracle entries of time info cell, ordered by strictly increasing oracle index
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct OracleEntries {
+    count: u8,
+    entries: [OracleEntry; ORACLE_ENTRIES_MAX_COUNT],
+}
+
+impl OracleEntries {
+    pub fn entries(&self) -> &[OracleEntry] {
+        &self.entries[..self.count as usize]
+    }
+
+    pub fn median_timestamp(&self) -> Option<u64> {
+        let mut timestamps = [0_u64; ORACLE_ENTRIES_MAX_COUNT];
+        for (timestamp, entry) in timestamps.iter_mut().zip(self.entries()) {
+            *timestamp = entry.timestamp;
+        }
+        median_timestamp(&mut timestamps[..self.count as usize])
+    }
+}
+
+impl TryFrom<&[u8]> for OracleEntries {
+    type Error = ParseError;
+
+    fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+        let (count, data) = match data.split_first() {
+            Some((count, data)) => (*count as usize, data),
+            None => return Err(ParseError::InvalidOracleEntries),
+        };
+        if count == 0 || count > ORACLE_ENTRIES_MAX_COUNT || data.len() != count * ORACLE_ENTRY_LEN {
+            return Err(ParseError::InvalidOracleEntries);
+        }
+        let mut oracle_entries = OracleEntries { count: count as u8, ..Default::default() };
+        for (entry, data) in oracle_entries.entries.iter_mut().zip(data.chunks_exact(ORACLE_ENTRY_LEN)) {
+            let mut timestamp = [0_u8; 8];
+            timestamp.copy_from_slice(&data[1..]);
+            *entry = OracleEntry { oracle_index: data[0], timestamp: u64::from_be_bytes(timestamp) };
+        }
+        //every oracle has one entry at most
+        if oracle_entries.entries().windows(2).any(|pair| pair[0].oracle_index >= pair[1].oracle_index) {
+            return Err(ParseError::InvalidOracleEntries);
+        }
+        Ok(oracle_entries)
+    }
+}
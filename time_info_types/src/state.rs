@@ -0,0 +1,95 @@
+use core::convert::TryFrom;
+
+use crate::args::{
+    next_args_field,
+    parse_hash,
+    split_args_out_point,
+    ARGS_FIELD_ADMIN_LOCK_HASH,
+    ARGS_FIELD_OPERATOR_LOCK_HASH,
+    TIME_INFO_ARGS_OUT_POINT_LEN,
+};
+use crate::error::ParseError;
+
+//time_index_state_cell_data = index as u8 | N as u8
+pub const TIME_INDEX_STATE_CELL_DATA_LEN: usize = 2;
+
+//script args = out_point(txHash + index) | field | field ..., field ids are the same as time info cell's
+pub const TIME_INDEX_STATE_ARGS_OUT_POINT_LEN: usize = TIME_INFO_ARGS_OUT_POINT_LEN;
+//time info family = time info type script code hash | hash type as u8 | out point in time info args
+pub const ARGS_FIELD_TIME_INFO_FAMILY: u8 = 15;
+pub const TIME_INFO_FAMILY_LEN: usize = 32 + 1 + TIME_INFO_ARGS_OUT_POINT_LEN;
+
+/// Time info cells which are created with the same out point in args by the same time info type script
+#[derive(Clone, Copy)]
+pub struct TimeInfoFamily {
+    pub code_hash: [u8; 32],
+    pub hash_type: u8,
+    pub args_out_point: [u8; TIME_INFO_ARGS_OUT_POINT_LEN],
+}
+
+#[derive(Default)]
+pub struct TimeIndexStateArgs {
+    pub admin_lock_hash: Option<[u8; 32]>,
+    pub operator_lock_hash: Option<[u8; 32]>,
+    pub time_info_family: Option<TimeInfoFamily>,
+}
+
+fn parse_time_info_family(value: &[u8]) -> Result<TimeInfoFamily, ParseError> {
+    if value.len() != TIME_INFO_FAMILY_LEN {
+        return Err(ParseError::InvalidArgument);
+    }
+    let (code_hash, value) = value.split_at(32);
+    let (hash_type, args_out_point) = value.split_at(1);
+    Ok(TimeInfoFamily {
+        code_hash: parse_hash(code_hash)?,
+        hash_type: hash_type[0],
+        args_out_point: <[u8; TIME_INFO_ARGS_OUT_POINT_LEN]>::try_from(args_out_point).
+            map_err(|_| ParseError::InvalidArgument)?,
+    })
+}
+
+pub fn parse_time_index_state_args(script_args: &[u8]) -> Result<TimeIndexStateArgs, ParseError> {
+    let (_, mut fields) = split_args_out_point(script_args)?;
+    let mut state_args = TimeIndexStateArgs::default();
+    while !fields.is_empty() {
+        let (field_id, value, rest) = next_args_field(fields)?;
+        match field_id {
+            ARGS_FIELD_ADMIN_LOCK_HASH if state_args.admin_lock_hash.is_none() => {
+                state_args.admin_lock_hash = Some(parse_hash(value)?);
+            }
+            ARGS_FIELD_OPERATOR_LOCK_HASH if state_args.operator_lock_hash.is_none() => {
+                state_args.operator_lock_hash = Some(parse_hash(value)?);
+            }
+            ARGS_FIELD_TIME_INFO_FAMILY if state_args.time_info_family.is_none() => {
+                state_args.time_info_family = Some(parse_time_info_family(value)?);
+            }
+            //unknown or duplicated field
+            _ => return Err(ParseError::InvalidArgument),
+        }
+        fields = rest;
+    }
+    Ok(state_args)
+}
+
+pub fn encode_time_index_state_cell_data(index: u8, ring_size: u8) -> [u8; TIME_INDEX_STATE_CELL_DATA_LEN] {
+    [index, ring_size]
+}
+
+/// Time index state cell data, index as u8 | N as u8
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeIndexStateData {
+    pub index: u8,
+    pub ring_size: u8,
+}
+
+impl TryFrom<&[u8]> for TimeIndexStateData {
+    type Error = ParseError;
+
+    fn try_from(cell_data: &[u8]) -> Result<Self, Self::Error> {
+        match cell_data {
+            [] => Err(ParseError::EmptyCellData),
+            [index, ring_size] => Ok(TimeIndexStateData { index: *index, ring_size: *ring_size }),
+            _ => Err(ParseError::InvalidCellDataLength),
+        }
+    }
+}